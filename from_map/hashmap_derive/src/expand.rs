@@ -1,6 +1,27 @@
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Data, DeriveInput, Error, Fields};
+use syn::{Attribute, Data, DeriveInput, Error, Fields, Meta, NestedMeta};
+
+/// Returns `true` if the field has the attribute `#[from_map(skip)]`.
+fn is_skipped(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        if !attr.path.is_ident("from_map") {
+            return false;
+        }
+
+        if let Ok(Meta::List(list)) = attr.parse_meta() {
+            list.nested.iter().any(|item| {
+                if let NestedMeta::Meta(Meta::Path(path)) = item {
+                    path.is_ident("skip")
+                } else {
+                    false
+                }
+            })
+        } else {
+            false
+        }
+    })
+}
 
 pub fn derive(input: &DeriveInput) -> syn::Result<TokenStream> {
     let mut errors = vec![];
@@ -12,6 +33,11 @@ pub fn derive(input: &DeriveInput) -> syn::Result<TokenStream> {
         if let Data::Struct(s) = &input.data {
             if let Fields::Named(named) = &s.fields {
                 for field in &named.named {
+                    // skipped fields are not part of the map
+                    if is_skipped(&field.attrs) {
+                        continue;
+                    }
+
                     if let Some(ident) = &field.ident {
                         result.push(ident.clone());
                         types.push(field.ty.clone());
@@ -10,7 +10,7 @@ mod expand;
 use proc_macro::TokenStream;
 use syn::DeriveInput;
 
-#[proc_macro_derive(FromMap)]
+#[proc_macro_derive(FromMap, attributes(from_map))]
 pub fn from_hashmap(input: TokenStream) -> TokenStream {
     let input = syn::parse_macro_input!(input as DeriveInput);
 
@@ -45,6 +45,30 @@ mod tests {
         pub rename: bool,
     }
 
+    #[derive(Default, FromMap, Debug, PartialEq)]
+    struct Skipped {
+        pub enabled: bool,
+        #[from_map(skip)]
+        pub level: usize,
+    }
+
+    #[test]
+    fn test_skip() {
+        let mut data = HashMap::new();
+        data.insert("enabled", true);
+
+        let result = Skipped::from_map(&data);
+
+        assert_eq!(
+            result,
+            Skipped {
+                enabled: true,
+                level: 0,
+            }
+        );
+        assert_eq!(result.as_map(), data);
+    }
+
     #[test]
     fn it_works() {
         let mut data = HashMap::new();
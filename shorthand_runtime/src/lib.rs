@@ -0,0 +1,101 @@
+#![warn(clippy::pedantic, clippy::nursery)]
+//! This crate has the types, that are used by the code derived by
+//! [`shorthand`](https://docs.rs/shorthand), which can not be exported by a
+//! proc-macro crate.
+use std::any::Any;
+use std::error::Error;
+use std::fmt;
+
+/// Information about a field of a struct, which is derived by `shorthand` with
+/// `#[shorthand(field_info)]` and available as `Example::FIELDS`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FieldInfo {
+    /// The name of the field.
+    pub name: &'static str,
+    /// The type of the field, as it is written in the struct.
+    pub type_name: &'static str,
+    /// The names of the functions, that are derived for the field.
+    pub accessors: &'static [&'static str],
+    /// `true`, if the field can not be changed with a derived function.
+    pub read_only: bool,
+    /// `true`, if no functions are derived for the field.
+    pub skipped: bool,
+    /// `true`, if the getter returns a copy of the field.
+    pub copy: bool,
+}
+
+impl FieldInfo {
+    /// Returns `true`, if a function with the `name` is derived for the field.
+    #[must_use]
+    pub fn has_accessor(&self, name: &str) -> bool { self.accessors.contains(&name) }
+}
+
+/// The error of `set_dyn`, which is derived by `shorthand` with
+/// `#[shorthand(dynamic)]`.
+#[derive(Debug)]
+pub enum SetError {
+    /// The struct has no field with the name or the field is skipped.
+    UnknownField,
+    /// The field has no setter.
+    ReadOnly,
+    /// The value does not have the type of the field. The value is returned, so
+    /// it is not lost.
+    WrongType(Box<dyn Any>),
+    /// The fallible verify function rejected the value, which has not been set.
+    /// The error of the verify function is returned.
+    Invalid(Box<dyn Any>),
+}
+
+impl fmt::Display for SetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownField => write!(f, "unknown field"),
+            Self::ReadOnly => write!(f, "the field has no setter"),
+            Self::WrongType(_) => write!(f, "the value does not have the type of the field"),
+            Self::Invalid(_) => write!(f, "the value has been rejected by the verify function"),
+        }
+    }
+}
+
+impl Error for SetError {}
+
+/// The error of the `set_<field>_from_str` functions and
+/// `set_by_name_from_str`, which are derived by `shorthand` with
+/// `#[shorthand(enable(parse))]`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FieldParseError {
+    /// The struct has no field with the name, that can be parsed.
+    UnknownField(String),
+    /// The string could not be parsed into the type of the field or the
+    /// fallible verify function of the field rejected the parsed value.
+    Invalid {
+        /// The name of the field.
+        field: &'static str,
+        /// The error of `FromStr` or of the verify function.
+        message: String,
+    },
+}
+
+impl FieldParseError {
+    /// Returns the name of the field, that could not be set.
+    #[must_use]
+    pub fn field(&self) -> &str {
+        match self {
+            Self::UnknownField(field) => field,
+            Self::Invalid { field, .. } => field,
+        }
+    }
+}
+
+impl fmt::Display for FieldParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownField(field) => write!(f, "unknown field `{field}`"),
+            Self::Invalid { field, message } => {
+                write!(f, "invalid value for field `{field}`: {message}")
+            }
+        }
+    }
+}
+
+impl Error for FieldParseError {}
@@ -1,16 +1,23 @@
 use std::collections::HashMap;
+use std::fmt;
 
 use from_map::FromMap;
+use quote::quote;
 use syn::spanned::Spanned as _;
-use syn::{Meta, NestedMeta};
+use syn::{Attribute, Meta, NestedMeta};
 
 use crate::error::Error;
-use crate::utils::MetaExt;
 use crate::utils::Spanned;
+use crate::utils::{AttributeExt, MetaExt};
 
 #[derive(Default)]
-pub(crate) struct AttributesBuilder {
+pub struct AttributesBuilder {
     fields: HashMap<Spanned<&'static str>, bool>,
+    presets: Vec<Spanned<&'static str>>,
+    /// The attributes, that are changed by the defaults of the configuration.
+    /// Repeating them is not redundant, because the struct does not have to
+    /// know the configuration.
+    configured: Vec<&'static str>,
     errors: Vec<Error>,
 }
 
@@ -20,10 +27,17 @@ What should trigger redundant error:
 - disabling an attribute, that is already disabled
 - enabling/disabling an attribute multiple times
   #[shorthand(enable(const_fn, const_fn))]
+- applying a preset, that would not change any attribute
+- enabling/disabling an attribute, that has already been set by a preset
+  #[shorthand(readonly, disable(set))]
+
+What should not trigger redundant error:
+- enabling/disabling an attribute, that has been set by the defaults of the
+  configuration
 */
 
 impl AttributesBuilder {
-    const FIELDS: [&'static str; 19] = [
+    const FIELDS: [&'static str; 23] = [
         "option_as_ref",
         "const_fn",
         "primitive_copy",
@@ -43,8 +57,99 @@ impl AttributesBuilder {
         "collection_magic",
         "strip_option",
         "clone",
+        "guard",
+        "doc",
+        "into_field",
+        "parse",
+    ];
+    /// The presets and the attributes they set.
+    pub const PRESETS: [(&'static str, &'static [(&'static str, bool)]); 3] = [
+        (
+            "readonly",
+            &[
+                ("get", true),
+                ("set", false),
+                ("try_into", false),
+                ("get_mut", false),
+                ("collection_magic", false),
+            ],
+        ),
+        (
+            "writeonly",
+            &[("get", false), ("get_mut", false), ("set", true)],
+        ),
+        (
+            "opaque",
+            &[
+                ("get", false),
+                ("set", false),
+                ("try_into", false),
+                ("get_mut", false),
+                ("collection_magic", false),
+            ],
+        ),
     ];
 
+    pub fn with_configured(&mut self, configured: &[&'static str]) -> &mut Self {
+        self.configured = configured.to_vec();
+        self
+    }
+
+    fn is_configured(&self, field: &str) -> bool { self.configured.contains(&field) }
+
+    pub fn is_preset(name: &str) -> bool { Self::PRESETS.iter().any(|(preset, _)| *preset == name) }
+
+    pub fn push_preset(&mut self, item: &Meta) -> &mut Self {
+        let name = item.to_string();
+
+        if let Meta::Path(_) = &item {
+            if let Some((preset, _)) = Self::PRESETS.iter().find(|(preset, _)| *preset == name) {
+                let preset = Spanned::new(*preset).with_span(&item.span());
+
+                // error for this invariant:
+                // #[shorthand(readonly, readonly)]
+                if self.presets.contains(&preset) {
+                    self.errors
+                        .push(Error::duplicate_field(*preset.inner()).with_span(&item));
+                } else {
+                    self.presets.push(preset);
+                }
+            } else {
+                unreachable!("`{}` is not a preset", name);
+            }
+        } else {
+            self.errors
+                .push(Error::unexpected_meta(item).with_alts(["Path"]));
+        }
+
+        self
+    }
+
+    /// Applies the presets to `result`. A preset is redundant, if it does not
+    /// change any attribute.
+    fn apply_presets(&mut self, result: &mut Attributes) {
+        for preset in &self.presets {
+            let (_, flags) = Self::PRESETS
+                .iter()
+                .find(|(name, _)| name == preset.inner())
+                .expect("unknown preset");
+
+            let map = result.as_map();
+
+            if flags
+                .iter()
+                .all(|(field, value)| map.get(field) == Some(value) && !self.is_configured(field))
+            {
+                self.errors.push(
+                    Error::redundant_field(*preset.inner(), Some("applied"))
+                        .with_span(&preset.span()),
+                );
+            }
+
+            result.with_map(&flags.iter().copied().collect());
+        }
+    }
+
     pub fn push_meta(&mut self, ident: &str, item: &Meta) -> &mut Self {
         let state = {
             match ident {
@@ -106,7 +211,7 @@ impl AttributesBuilder {
                         if unknown {
                             self.errors.push(
                                 Error::unknown_field(name.as_str())
-                                    .with_alts(&Self::FIELDS)
+                                    .with_alts(Self::FIELDS)
                                     .with_span(&inner),
                             );
                         }
@@ -126,6 +231,24 @@ impl AttributesBuilder {
     }
 
     pub fn build_with(mut self, mut result: Attributes) -> Result<Attributes, Error> {
+        // presets are applied before the explicit attributes, so enabling or
+        // disabling an attribute, that is set by a preset is redundant.
+        self.apply_presets(&mut result);
+
+        // `inline` is not a bool and therefore not part of the map
+        if let Some((field, value)) = self.fields.remove_entry(&Spanned::new("inline")) {
+            if value == result.inline.is_enabled() && !self.is_configured("inline") {
+                self.errors.push(
+                    Error::redundant_field(*field.inner(), Some(result.inline.state()))
+                        .with_span(&field.span()),
+                );
+            } else if value {
+                result.inline = Inline::default();
+            } else {
+                result.inline = Inline::Disabled;
+            }
+        }
+
         // loop through all fields of result:
         for (field, v) in result.as_map() {
             // TODO: this is temporary and should be removed as soon as those attributes
@@ -135,7 +258,7 @@ impl AttributesBuilder {
             }
 
             if let Some((field, value)) = self.fields.get_key_value(&Spanned::new(field)) {
-                if v == *value {
+                if v == *value && !self.is_configured(field.inner()) {
                     let state = {
                         if v {
                             "enabled"
@@ -172,12 +295,114 @@ impl AttributesBuilder {
     // }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, FromMap)]
-pub(crate) struct Attributes {
+/// The level of the `#[inline]` attribute, that is added to the generated
+/// functions.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd)]
+pub enum Inline {
+    /// No `#[inline]` attribute.
+    Disabled,
+    /// `#[inline]`
+    Hint,
+    /// `#[inline(always)]`
+    Always,
+    /// `#[inline(never)]`
+    Never,
+}
+
+// `#[default]` on a variant would raise the minimum supported Rust version
+#[allow(clippy::derivable_impls)]
+impl Default for Inline {
+    fn default() -> Self { Self::Always }
+}
+
+impl Inline {
+    const LEVELS: [&'static str; 3] = ["hint", "always", "never"];
+
+    /// Parses `inline(hint)`, `inline(always)` or `inline(never)`.
+    pub fn from_meta(item: &Meta) -> Result<Self, Error> {
+        let list = {
+            if let Meta::List(list) = item {
+                list
+            } else {
+                return Err(Error::unexpected_meta(item).with_alts(["List"]));
+            }
+        };
+
+        let mut nested = list.nested.iter();
+
+        match (nested.next(), nested.next()) {
+            (Some(NestedMeta::Meta(Meta::Path(path))), None) => {
+                match path.get_ident().map(ToString::to_string).as_deref() {
+                    Some("hint") => Ok(Self::Hint),
+                    Some("always") => Ok(Self::Always),
+                    Some("never") => Ok(Self::Never),
+                    _ => {
+                        Err(Error::unknown_field(&quote!(#path).to_string())
+                            .with_alts(Self::LEVELS)
+                            .with_span(path))
+                    }
+                }
+            }
+            (Some(NestedMeta::Lit(value)), None) => Err(Error::unexpected_lit(value)),
+            (Some(NestedMeta::Meta(meta)), None) => {
+                Err(Error::unexpected_meta(meta).with_alts(["Path"]))
+            }
+            (_, Some(extra)) => {
+                Err(Error::unexpected_field(&quote!(#extra).to_string())
+                    .with_alts(Self::LEVELS)
+                    .with_span(extra))
+            }
+            (None, None) => {
+                Err(
+                    Error::custom(format!("expected one of {}", Self::LEVELS.join(", ")))
+                        .with_span(item),
+                )
+            }
+        }
+    }
+
+    pub fn is_enabled(self) -> bool { self != Self::Disabled }
+
+    /// The state of the attribute for error messages, for example `enabled`.
+    fn state(self) -> String {
+        match self {
+            Self::Disabled => "disabled".to_string(),
+            _ => format!("enabled with `inline({self})`"),
+        }
+    }
+
+    pub fn to_attribute(self) -> Option<Attribute> {
+        let tokens = {
+            match self {
+                Self::Disabled => return None,
+                Self::Hint => quote!(#[inline]),
+                Self::Always => quote!(#[inline(always)]),
+                Self::Never => quote!(#[inline(never)]),
+            }
+        };
+
+        Some(Attribute::from_token_stream(tokens).unwrap())
+    }
+}
+
+impl fmt::Display for Inline {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Disabled => write!(f, "disabled"),
+            Self::Hint => write!(f, "hint"),
+            Self::Always => write!(f, "always"),
+            Self::Never => write!(f, "never"),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, FromMap)]
+pub struct Attributes {
     pub option_as_ref: bool,
     pub const_fn: bool,
     pub primitive_copy: bool,
-    pub inline: bool,
+    #[from_map(skip)]
+    pub inline: Inline,
     pub must_use: bool,
     pub copy: bool,
     pub get: bool,
@@ -193,15 +418,73 @@ pub(crate) struct Attributes {
     pub collection_magic: bool,
     pub strip_option: bool,
     pub clone: bool,
+    pub guard: bool,
+    pub doc: bool,
+    pub into_field: bool,
+    pub parse: bool,
 }
 
 impl Attributes {
     pub fn builder() -> AttributesBuilder { AttributesBuilder::default() }
 
-    pub fn with_meta(result: Self, ident: &str, item: &Meta) -> Result<Self, Error> {
+    pub fn with_meta(
+        result: Self,
+        ident: &str,
+        item: &Meta,
+        configured: &[&'static str],
+    ) -> Result<Self, Error> {
+        let mut builder = Self::builder();
+
+        builder.with_configured(configured).push_meta(ident, item);
+
+        builder.build_with(result)
+    }
+
+    /// Sets the level of the `#[inline]` attribute, for example with
+    /// `#[shorthand(inline(hint))]`.
+    pub fn with_inline(
+        mut result: Self,
+        item: &Meta,
+        configured: &[&'static str],
+    ) -> Result<Self, Error> {
+        let inline = Inline::from_meta(item)?;
+
+        if inline == result.inline && !configured.contains(&"inline") {
+            return Err(
+                Error::redundant_field("inline", Some(result.inline.state())).with_span(item),
+            );
+        }
+
+        result.inline = inline;
+
+        Ok(result)
+    }
+
+    /// Returns the names of the attributes, that are different in `other`.
+    pub fn changed(&self, other: &Self) -> Vec<&'static str> {
+        let map = other.as_map();
+        let mut result = self
+            .as_map()
+            .into_iter()
+            .filter(|(field, value)| map.get(field) != Some(value))
+            .map(|(field, _)| field)
+            .collect::<Vec<_>>();
+
+        if self.inline != other.inline {
+            result.push("inline");
+        }
+
+        result
+    }
+
+    pub fn with_preset(
+        result: Self,
+        item: &Meta,
+        configured: &[&'static str],
+    ) -> Result<Self, Error> {
         let mut builder = Self::builder();
 
-        builder.push_meta(ident, item);
+        builder.with_configured(configured).push_preset(item);
 
         builder.build_with(result)
     }
@@ -213,7 +496,7 @@ impl Default for Attributes {
             option_as_ref: true,
             const_fn: false,
             primitive_copy: true,
-            inline: true,
+            inline: Inline::default(),
             must_use: false,
             copy: false,
             get: true,
@@ -228,6 +511,10 @@ impl Default for Attributes {
             collection_magic: false,
             strip_option: false,
             clone: false,
+            guard: false,
+            doc: true,
+            into_field: false,
+            parse: false,
         }
     }
 }
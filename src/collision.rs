@@ -0,0 +1,242 @@
+//! This module detects derived functions with the same name, which would
+//! otherwise only be reported by rustc as duplicate definitions. Names, that
+//! are already used by the user, can be reserved with
+//! `#[shorthand(reserved(new, len))]`.
+
+use proc_macro2::{TokenStream, TokenTree};
+use syn::{Ident, Meta, NestedMeta};
+
+use crate::error::Error;
+
+#[derive(Debug, Clone, Default)]
+pub struct Reserved(Vec<Ident>);
+
+impl Reserved {
+    /// Parses `reserved(new, len)`.
+    pub fn update(&mut self, meta: &Meta) -> Result<(), Error> {
+        let list = {
+            if let Meta::List(list) = meta {
+                list
+            } else {
+                return Err(Error::unexpected_meta(meta).with_alts(["List"]));
+            }
+        };
+
+        let mut errors = vec![];
+
+        for nested in &list.nested {
+            match nested {
+                NestedMeta::Meta(Meta::Path(path)) if path.get_ident().is_some() => {
+                    self.0.extend(path.get_ident().cloned());
+                }
+                NestedMeta::Meta(meta) => {
+                    errors.push(Error::unexpected_meta(meta).with_alts(["Path"]));
+                }
+                NestedMeta::Lit(value) => {
+                    errors.push(Error::unexpected_lit(value).with_span(value));
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::multiple(errors))
+        }
+    }
+
+    fn contains(&self, name: &Ident) -> bool { self.get(name).is_some() }
+
+    /// Returns the reserved name, which has the span of the attribute.
+    fn get(&self, name: &Ident) -> Option<&Ident> {
+        self.0.iter().find(|reserved| *reserved == name)
+    }
+}
+
+/// Returns the names of the functions in `tokens`, which are the idents after
+/// `fn`. The bodies of the functions are groups, so nested functions are not
+/// included.
+pub fn function_names(tokens: &TokenStream) -> Vec<Ident> {
+    let mut result = vec![];
+    let mut is_fn = false;
+
+    for token in tokens.clone() {
+        if let TokenTree::Ident(ident) = token {
+            if is_fn {
+                result.push(ident);
+                is_fn = false;
+            } else {
+                is_fn = ident == "fn";
+            }
+        } else {
+            is_fn = false;
+        }
+    }
+
+    result
+}
+
+/// Checks, that every function is only derived once and that no function has
+/// a reserved name. The `functions` are the names of the derived functions of
+/// each field and `derived` are the names of the functions and constants, that
+/// are derived for the entire struct (like `new` or `FIELDS`).
+pub fn check(
+    functions: &[(&Ident, Vec<Ident>)],
+    derived: &[Ident],
+    reserved: &Reserved,
+) -> Result<(), Error> {
+    let mut errors = vec![];
+    let mut seen: Vec<(&Ident, &Ident)> = vec![];
+
+    for name in derived {
+        if let Some(span) = reserved.get(name) {
+            errors.push(
+                Error::custom(format!(
+                    "the item `{name}`, that is derived for the struct, has a reserved name"
+                ))
+                .with_span(span),
+            );
+        }
+    }
+
+    for (field, names) in functions {
+        for name in names {
+            if reserved.contains(name) {
+                errors.push(
+                    Error::custom(format!(
+                        "the function `{name}` of the field `{field}` has a reserved name"
+                    ))
+                    .with_span(field),
+                );
+            }
+
+            if derived.contains(name) {
+                errors.push(
+                    Error::custom(format!(
+                        "the function `{name}` of the field `{field}` is also derived for the struct"
+                    ))
+                    .with_span(field),
+                );
+            }
+
+            if let Some((other, _)) = seen.iter().find(|(_, other)| other == &name) {
+                if other == field {
+                    errors.push(
+                        Error::custom(format!(
+                            "the function `{name}` is derived twice for the field `{field}`"
+                        ))
+                        .with_span(field),
+                    );
+                } else {
+                    errors.push(
+                        Error::custom(format!(
+                            "the function `{name}` of the field `{other}` is also derived for the field \
+                             `{field}`"
+                        ))
+                        .with_span(other),
+                    );
+                    errors.push(
+                        Error::custom(format!(
+                            "the function `{name}` of the field `{field}` is also derived for the field \
+                             `{other}`"
+                        ))
+                        .with_span(field),
+                    );
+                }
+            } else {
+                seen.push((field, name));
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::multiple(errors))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use proc_macro2::Span;
+    use quote::quote;
+
+    fn ident(value: &str) -> Ident { Ident::new(value, Span::call_site()) }
+
+    #[test]
+    fn test_function_names() {
+        let tokens = quote! {
+            #[inline(always)]
+            pub const fn value(&self) -> usize {
+                fn nested() {}
+                self.value
+            }
+
+            pub(crate) fn set_value(&mut self, value: usize) -> &mut Self { self }
+        };
+
+        assert_eq!(
+            function_names(&tokens),
+            vec![ident("value"), ident("set_value")]
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        let (foo, set_foo) = (ident("foo"), ident("set_foo"));
+
+        assert!(check(
+            &[
+                (&foo, vec![ident("foo"), ident("set_foo")]),
+                (&set_foo, vec![ident("get_set_foo")]),
+            ],
+            &[],
+            &Reserved::default()
+        )
+        .is_ok());
+
+        assert_eq!(
+            check(
+                &[
+                    (&foo, vec![ident("foo"), ident("set_foo")]),
+                    (&set_foo, vec![ident("set_foo")]),
+                ],
+                &[],
+                &Reserved::default()
+            )
+            .unwrap_err(),
+            Error::multiple(vec![
+                Error::custom(
+                    "the function `set_foo` of the field `foo` is also derived for the field \
+                     `set_foo`"
+                ),
+                Error::custom(
+                    "the function `set_foo` of the field `set_foo` is also derived for the \
+                     field `foo`"
+                ),
+            ])
+        );
+
+        let mut reserved = Reserved::default();
+        reserved
+            .update(&syn::parse_str("reserved(foo)").unwrap())
+            .unwrap();
+
+        assert_eq!(
+            check(&[(&foo, vec![ident("foo")])], &[], &reserved).unwrap_err(),
+            Error::custom("the function `foo` of the field `foo` has a reserved name")
+        );
+
+        assert_eq!(
+            check(&[(&foo, vec![ident("new")])], &[ident("new")], &reserved).unwrap_err(),
+            Error::custom("the function `new` of the field `foo` is also derived for the struct")
+        );
+
+        assert_eq!(
+            check(&[], &[ident("foo")], &reserved).unwrap_err(),
+            Error::custom("the item `foo`, that is derived for the struct, has a reserved name")
+        );
+    }
+}
@@ -0,0 +1,358 @@
+//! This module reads the `shorthand.toml` file of the crate, that uses the
+//! derive. If there is no such file, the `[package.metadata.shorthand]` table
+//! of the `Cargo.toml` is used instead.
+//!
+//! The `defaults` table replaces the defaults of all structs and the file can
+//! contain named profiles, which can be applied to a struct with
+//! `#[shorthand(profile = "name")]`:
+//!
+//! ```toml
+//! [defaults]
+//! disable = ["inline"]
+//!
+//! [profile.domain]
+//! enable = ["must_use", "into"]
+//! disable = ["primitive_copy"]
+//! visibility = "pub(crate)"
+//! ```
+//!
+//! Each table is converted into `#[shorthand(...)]` attributes, so they are
+//! validated in the same way as the attributes of a struct.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Attribute, Ident, LitStr};
+
+use crate::error::Error;
+use crate::utils::AttributeExt;
+
+#[derive(Debug, Clone, Default)]
+pub struct Config {
+    path: Option<PathBuf>,
+    defaults: Vec<Attribute>,
+    profiles: BTreeMap<String, Vec<Attribute>>,
+}
+
+impl Config {
+    const FIELDS: [&'static str; 6] = [
+        "enable",
+        "disable",
+        "visibility",
+        "rename",
+        "inline",
+        "bool_prefix",
+    ];
+    const FILE: &'static str = "shorthand.toml";
+    const MANIFEST: &'static str = "Cargo.toml";
+
+    /// Reads the `shorthand.toml` or the `Cargo.toml` in the directory of the
+    /// crate, that is currently compiled. If there is no configuration, an
+    /// empty `Config` is returned.
+    pub fn load() -> Result<Self, Error> {
+        let dir = {
+            if let Some(dir) = env::var_os("CARGO_MANIFEST_DIR") {
+                PathBuf::from(dir)
+            } else {
+                return Ok(Self::default());
+            }
+        };
+
+        let path = dir.join(Self::FILE);
+        let manifest = dir.join(Self::MANIFEST);
+
+        let (mut result, path) = {
+            if path.is_file() {
+                (Self::parse(&Self::read(&path)?)?, path)
+            } else if manifest.is_file() {
+                match Self::parse_manifest(&Self::read(&manifest)?)? {
+                    Some(result) => (result, manifest),
+                    None => return Ok(Self::default()),
+                }
+            } else {
+                return Ok(Self::default());
+            }
+        };
+
+        result.path = Some(path);
+
+        Ok(result)
+    }
+
+    fn read(path: &Path) -> Result<String, Error> {
+        fs::read_to_string(path)
+            .map_err(|e| Error::custom(format!("failed to read `{}`: {}", path.display(), e)))
+    }
+
+    pub fn parse(input: &str) -> Result<Self, Error> {
+        let value = input
+            .parse::<toml::Value>()
+            .map_err(|e| Error::custom(format!("failed to parse `{}`: {}", Self::FILE, e)))?;
+
+        Self::from_value(&value)
+    }
+
+    /// Parses the `[package.metadata.shorthand]` table of a `Cargo.toml`.
+    pub fn parse_manifest(input: &str) -> Result<Option<Self>, Error> {
+        let value = input
+            .parse::<toml::Value>()
+            .map_err(|e| Error::custom(format!("failed to parse `{}`: {}", Self::MANIFEST, e)))?;
+
+        value
+            .get("package")
+            .and_then(|package| package.get("metadata"))
+            .and_then(|metadata| metadata.get("shorthand"))
+            .map(Self::from_value)
+            .transpose()
+    }
+
+    fn from_value(value: &toml::Value) -> Result<Self, Error> {
+        let mut result = Self::default();
+
+        if let Some(defaults) = value.get("defaults") {
+            result.defaults = Self::parse_table("defaults", defaults)?;
+        }
+
+        if let Some(profiles) = value.get("profile") {
+            let profiles = profiles
+                .as_table()
+                .ok_or_else(|| Error::custom("`profile` must be a table"))?;
+
+            for (name, table) in profiles {
+                result
+                    .profiles
+                    .insert(name.clone(), Self::parse_table(name, table)?);
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Converts a table like `{ enable = ["into"], visibility = "pub" }` into
+    /// the attributes `#[shorthand(enable(into))]` and
+    /// `#[shorthand(visibility("pub"))]`.
+    fn parse_table(name: &str, table: &toml::Value) -> Result<Vec<Attribute>, Error> {
+        let table = table
+            .as_table()
+            .ok_or_else(|| Error::custom(format!("`{name}` must be a table")))?;
+
+        let mut errors = vec![];
+        let mut result = vec![];
+
+        for (key, value) in table {
+            let tokens = {
+                match key.as_str() {
+                    "enable" | "disable" => Self::parse_idents(key, value),
+                    "visibility" | "rename" => Self::parse_str(key, value),
+                    "inline" => Self::parse_ident(key, value),
+                    "bool_prefix" => Self::parse_name_value(key, value),
+                    _ => Err(Error::unknown_field(key.as_str()).with_alts(Self::FIELDS)),
+                }
+            };
+
+            match tokens.and_then(|tokens| {
+                Attribute::from_token_stream(quote!(#[shorthand(#tokens)])).map_err(Error::syn)
+            }) {
+                Ok(attribute) => result.push(attribute),
+                Err(err) => errors.push(err),
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(Error::multiple(errors));
+        }
+
+        Ok(result)
+    }
+
+    fn parse_idents(key: &str, value: &toml::Value) -> Result<TokenStream, Error> {
+        let key = Ident::new(key, Span::call_site());
+        let values = value
+            .as_array()
+            .ok_or_else(|| Error::custom(format!("`{key}` must be an array of strings")))?;
+
+        let mut idents = vec![];
+
+        for value in values {
+            let value = value
+                .as_str()
+                .ok_or_else(|| Error::custom(format!("`{key}` must be an array of strings")))?;
+
+            idents.push(syn::parse_str::<Ident>(value).map_err(Error::syn)?);
+        }
+
+        Ok(quote!(#key(#(#idents),*)))
+    }
+
+    fn parse_ident(key: &str, value: &toml::Value) -> Result<TokenStream, Error> {
+        let key = Ident::new(key, Span::call_site());
+        let value = value
+            .as_str()
+            .ok_or_else(|| Error::custom(format!("`{key}` must be a string")))?;
+        let value = syn::parse_str::<Ident>(value).map_err(Error::syn)?;
+
+        Ok(quote!(#key(#value)))
+    }
+
+    fn parse_str(key: &str, value: &toml::Value) -> Result<TokenStream, Error> {
+        let key = Ident::new(key, Span::call_site());
+        let value = value
+            .as_str()
+            .ok_or_else(|| Error::custom(format!("`{key}` must be a string")))?;
+        let value = LitStr::new(value, Span::call_site());
+
+        Ok(quote!(#key(#value)))
+    }
+
+    fn parse_name_value(key: &str, value: &toml::Value) -> Result<TokenStream, Error> {
+        let key = Ident::new(key, Span::call_site());
+        let value = value
+            .as_str()
+            .ok_or_else(|| Error::custom(format!("`{key}` must be a string")))?;
+        let value = LitStr::new(value, Span::call_site());
+
+        Ok(quote!(#key = #value))
+    }
+
+    /// The path of the file, that has been read.
+    pub const fn path(&self) -> Option<&PathBuf> { self.path.as_ref() }
+
+    /// The attributes, that replace the defaults of all structs.
+    pub const fn defaults(&self) -> &Vec<Attribute> { &self.defaults }
+
+    pub fn profile(&self, name: &str) -> Option<&Vec<Attribute>> { self.profiles.get(name) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_parse_profile() {
+        let config = Config::parse(
+            r#"
+            [profile.domain]
+            enable = ["must_use", "into"]
+            disable = ["primitive_copy"]
+            visibility = "pub(crate)"
+            "#,
+        )
+        .unwrap();
+
+        let attributes = config
+            .profile("domain")
+            .unwrap()
+            .iter()
+            .map(|attr| quote!(#attr).to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            attributes,
+            vec![
+                quote!(#[shorthand(disable(primitive_copy))]).to_string(),
+                quote!(#[shorthand(enable(must_use, into))]).to_string(),
+                quote!(#[shorthand(visibility("pub(crate)"))]).to_string(),
+            ]
+        );
+        assert!(config.profile("other").is_none());
+    }
+
+    #[test]
+    fn test_parse_defaults() {
+        let config = Config::parse(
+            r#"
+            [defaults]
+            disable = ["copy"]
+            inline = "hint"
+            rename = "get_{}"
+            bool_prefix = "is_"
+            "#,
+        )
+        .unwrap();
+
+        let attributes = config
+            .defaults()
+            .iter()
+            .map(|attr| quote!(#attr).to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            attributes,
+            vec![
+                quote!(#[shorthand(bool_prefix = "is_")]).to_string(),
+                quote!(#[shorthand(disable(copy))]).to_string(),
+                quote!(#[shorthand(inline(hint))]).to_string(),
+                quote!(#[shorthand(rename("get_{}"))]).to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_manifest() {
+        let config = Config::parse_manifest(
+            r#"
+            [package]
+            name = "example"
+
+            [package.metadata.shorthand.defaults]
+            visibility = "pub(crate)"
+
+            [package.metadata.shorthand.profile.domain]
+            enable = ["into"]
+            "#,
+        )
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(config.defaults().len(), 1);
+        assert!(config.profile("domain").is_some());
+
+        assert!(Config::parse_manifest("[package]\nname = \"example\"")
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_parse_table() {
+        let table = toml::toml! {
+            enable = ["into", "must_use"]
+            inline = "never"
+        };
+
+        let attributes = Config::parse_table("defaults", &table)
+            .unwrap()
+            .iter()
+            .map(|attr| quote!(#attr).to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            attributes,
+            vec![
+                quote!(#[shorthand(enable(into, must_use))]).to_string(),
+                quote!(#[shorthand(inline(never))]).to_string(),
+            ]
+        );
+
+        assert_eq!(
+            Config::parse_table("defaults", &toml::Value::from("into")).unwrap_err(),
+            Error::custom("`defaults` must be a table")
+        );
+        assert_eq!(
+            Config::parse_table("defaults", &toml::toml! { enable = "into" }).unwrap_err(),
+            Error::multiple(vec![Error::custom("`enable` must be an array of strings")])
+        );
+    }
+
+    #[test]
+    fn test_parse_unknown_field() {
+        assert_eq!(
+            Config::parse("[profile.domain]\nunknown = true").unwrap_err(),
+            Error::unknown_field("unknown").with_alts(&Config::FIELDS)
+        );
+    }
+}
@@ -0,0 +1,336 @@
+//! This module generates the constructor `new`, that is enabled with
+//! `#[shorthand(constructor)]`. Fields with `#[shorthand(default)]` or
+//! `#[shorthand(default = "expr")]` are not part of the arguments.
+//!
+//! The default values of the fields are also used by `reset()` and by the
+//! `Default` implementation, that is enabled with
+//! `#[shorthand(default_impl)]` on the struct.
+
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned, ToTokens};
+use syn::spanned::Spanned as _;
+use syn::{Attribute, Field, Generics, Lit, Meta, Type};
+
+use crate::doc;
+use crate::error::Error;
+use crate::expand::{generate_assertion, Generator};
+use crate::options::Options;
+use crate::utils::{AttributeExt, TypeExt};
+
+/// The value of a field, that is not passed to the constructor.
+#[derive(Debug, Clone)]
+pub enum DefaultValue {
+    /// `#[shorthand(default)]` uses `Default::default()`
+    Default,
+    /// `#[shorthand(default = "expr")]` uses the expression
+    Expr(TokenStream),
+}
+
+impl DefaultValue {
+    pub fn from_meta(meta: &Meta) -> Result<Self, Error> {
+        match meta {
+            Meta::Path(_) => Ok(Self::Default),
+            Meta::NameValue(pair) => {
+                if let Lit::Str(value) = &pair.lit {
+                    // the tokens will have the span of the string
+                    let expr = value.parse::<TokenStream>().map_err(Error::syn)?;
+
+                    if expr.is_empty() {
+                        return Err(
+                            Error::custom("the default value must not be empty").with_span(value)
+                        );
+                    }
+
+                    Ok(Self::Expr(expr))
+                } else {
+                    Err(Error::unexpected_lit(&pair.lit).with_alts(["string"]))
+                }
+            }
+            Meta::List(_) => Err(Error::unexpected_meta(meta).with_alts(["Path", "NameValue"])),
+        }
+    }
+
+    /// Returns an assertion, that the `field_type` implements `Default`, if
+    /// there is no explicit expression.
+    pub fn assertion(&self, field_type: &Type, generics: &Generics) -> Option<TokenStream> {
+        if matches!(self, Self::Default) {
+            Some(generate_assertion(
+                &quote!(_AssertDefault),
+                field_type,
+                generics,
+                &quote!(::core::default::Default),
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+impl ToTokens for DefaultValue {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Default => tokens.extend(quote!(::core::default::Default::default())),
+            Self::Expr(expr) => tokens.extend(quote!({ #expr })),
+        }
+    }
+}
+
+/// Returns the argument of the constructor and the value of the field, which
+/// is converted like in the setter (`into` and `strip_option`).
+fn argument(options: &Options, field: &Field) -> (TokenStream, TokenStream) {
+    let ident = &field.ident;
+    let field_type = &field.ty;
+
+    // tries to get the `T` from Option<T>
+    let option_arg = {
+        if field_type.is_ident("Option") {
+            field_type
+                .arguments()
+                .into_iter()
+                .find_map(|s| s.into_iter().last())
+        } else {
+            None
+        }
+    };
+
+    match (option_arg, options.attributes.into) {
+        (Some(arg), true) if options.attributes.strip_option => {
+            (
+                quote!(#ident: impl ::core::convert::Into<#arg>),
+                quote!(::core::option::Option::Some(::core::convert::Into::into(#ident))),
+            )
+        }
+        (Some(arg), true) => {
+            (
+                quote!(#ident: ::core::option::Option<impl ::core::convert::Into<#arg>>),
+                quote!(::core::option::Option::map(#ident, ::core::convert::Into::into)),
+            )
+        }
+        (_, true) => {
+            (
+                quote!(#ident: impl ::core::convert::Into<#field_type>),
+                quote!(::core::convert::Into::into(#ident)),
+            )
+        }
+        (Some(arg), false) if options.attributes.strip_option => {
+            (
+                quote!(#ident: #arg),
+                quote!(::core::option::Option::Some(#ident)),
+            )
+        }
+        (_, false) => (quote!(#ident: #field_type), quote!(#ident)),
+    }
+}
+
+/// Returns the error for a field, that would be initialized with
+/// `Default::default()` in a `const fn` constructor.
+fn not_const_default(field: &Field) -> Error {
+    Error::custom(
+        "a `const fn` constructor can not call `Default::default()`, the field needs a constant \
+         `default = \"...\"`",
+    )
+    .with_span(&field.ident)
+}
+
+/// Generates the constructor `new` for the `fields`, where `options` are the
+/// options of the struct.
+pub fn generate(options: &Options, fields: &[(Options, &Field)]) -> Result<TokenStream, Error> {
+    let mut errors = vec![];
+    let mut arguments = vec![];
+    let mut values = vec![];
+    let mut assertions = vec![];
+
+    let const_fn = options.attributes.const_fn;
+
+    for (field_options, field) in fields {
+        let ident = &field.ident;
+
+        if let Some(default) = &field_options.default {
+            if const_fn && matches!(default, DefaultValue::Default) {
+                errors.push(not_const_default(field));
+            }
+
+            // every assertion is in its own block, so the names do not collide
+            assertions.extend(default.assertion(&field.ty, &options.generics));
+            values.push(quote!(#ident: #default));
+        } else if field_options.attributes.skip
+            || (field_options.attributes.ignore_phantomdata && field.ty.is_ident("PhantomData"))
+        {
+            // fields, that are only excluded from the accessors (for example with `only`),
+            // are still arguments
+            if field.ty.is_ident("PhantomData") {
+                // `PhantomData` can be created in a `const fn`
+                values.push(quote!(#ident: ::core::marker::PhantomData));
+            } else {
+                if const_fn {
+                    errors.push(not_const_default(field));
+                }
+
+                // the error points at the type, if it does not implement `Default`
+                let field_type = &field.ty;
+                let value = quote_spanned! {field_type.span()=>
+                    <#field_type as ::core::default::Default>::default()
+                };
+
+                values.push(quote!(#ident: #value));
+            }
+        } else {
+            if const_fn && field_options.attributes.into {
+                errors.push(
+                    Error::custom("a `const fn` constructor can not convert a field with `into`")
+                        .with_span(ident),
+                );
+            }
+
+            let (argument, value) = argument(field_options, field);
+
+            arguments.push(argument);
+            values.push(quote!(#ident: #value));
+        }
+    }
+
+    if const_fn {
+        // the verify functions can not be `const` and `try_fn` would need `?`
+        if let Some(path) = options.verify.path() {
+            errors.push(
+                Error::custom("a `const fn` constructor can not call the `verify` functions")
+                    .with_span(path),
+            );
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(Error::multiple(errors));
+    }
+
+    let mut attributes: Vec<Attribute> = vec![];
+
+    if let Some(inline) = options.attributes.inline.to_attribute() {
+        attributes.push(inline);
+    }
+
+    if options.attributes.doc {
+        let ident = &options.ident;
+        let ty: Type = syn::parse2(quote!(#ident)).unwrap();
+
+        doc::document(&mut attributes, None, doc::NEW, ident, &ty, &[]);
+    }
+
+    if options.attributes.must_use {
+        attributes.push(Attribute::from_token_stream(quote!(#[must_use])).unwrap());
+    }
+
+    let visibility = options.visibility.set();
+    let const_fn = {
+        if const_fn {
+            quote![const]
+        } else {
+            quote![]
+        }
+    };
+
+    let result = quote!(__result);
+    let verify = &options.verify;
+
+    // the verify functions are called once, after all fields are set
+    let call = verify.call(&quote!(&#result)).map(|call| quote!(#call;));
+
+    if let (Some(try_call), Some(error)) = (verify.try_call(&quote!(&#result)), verify.error()) {
+        Ok(quote! {
+            #(#attributes)*
+            #visibility #const_fn fn new(#(#arguments),*) -> ::core::result::Result<Self, #error> {
+                #({ #assertions })*
+                let #result = Self { #(#values),* };
+                #call
+                #try_call?;
+                ::core::result::Result::Ok(#result)
+            }
+        })
+    } else {
+        Ok(quote! {
+            #(#attributes)*
+            #visibility #const_fn fn new(#(#arguments),*) -> Self {
+                #({ #assertions })*
+                let #result = Self { #(#values),* };
+                #call
+                #result
+            }
+        })
+    }
+}
+
+/// Generates `reset()`, which resets all fields, that have a `reset_<field>()`
+/// function, to their default values.
+pub fn reset(options: &Options, fields: &[(Options, &Field)]) -> Result<TokenStream, Error> {
+    let mut assignments = vec![];
+    let mut records = vec![];
+
+    for (field_options, field) in fields
+        .iter()
+        .filter(|(options, field)| !Generator::is_ignored(options, field) && options.attributes.set)
+    {
+        if let Some(default) = &field_options.default {
+            let (assignment, record) = Generator::assign(
+                field_options,
+                field.ident.as_ref().unwrap(),
+                &quote!(#default),
+            )?;
+
+            assignments.push(assignment);
+            records.push(record);
+        }
+    }
+
+    let mut attributes: Vec<Attribute> = vec![];
+
+    if let Some(inline) = options.attributes.inline.to_attribute() {
+        attributes.push(inline);
+    }
+
+    if options.attributes.doc {
+        let ident = &options.ident;
+        let ty: Type = syn::parse2(quote!(#ident)).unwrap();
+
+        doc::document(&mut attributes, None, doc::RESET_ALL, ident, &ty, &[]);
+    }
+
+    let visibility = options.visibility.set();
+    let verify = &options.verify;
+
+    // the events are recorded after all fields have been verified
+    Ok(quote! {
+        #(#attributes)*
+        #visibility fn reset(&mut self) -> &mut Self {
+            #(#assignments)*
+            #verify
+            #(#records)*
+            self
+        }
+    })
+}
+
+/// Implements `Default` for the struct, where fields without a default value
+/// are initialized with `Default::default()`.
+pub fn default_impl(options: &Options, fields: &[(Options, &Field)]) -> TokenStream {
+    let values = fields.iter().map(|(options, field)| {
+        let ident = &field.ident;
+
+        let default = options.default.as_ref().map_or_else(
+            || quote!(::core::default::Default::default()),
+            ToTokens::to_token_stream,
+        );
+
+        quote!(#ident: #default)
+    });
+
+    let ident = &options.ident;
+    let (impl_generics, ty_generics, where_clause) = options.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics ::core::default::Default for #ident #ty_generics #where_clause {
+            fn default() -> Self {
+                Self { #(#values),* }
+            }
+        }
+    }
+}
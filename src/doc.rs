@@ -0,0 +1,343 @@
+//! This module generates the documentation of the derived functions, which can
+//! be customized with `#[shorthand(doc(get = "...", set = "..."))]`.
+
+use proc_macro2::Span;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::{Attribute, Ident, Lit, Meta, NestedMeta, Token, Type};
+
+use crate::error::Error;
+use crate::parser::parse_shorthand;
+use crate::utils::{AttributeExt, PathExt};
+use crate::visibility::Visibilities;
+
+pub const GET: &str = "Returns a reference to `{field}`.";
+pub const GET_OPTION: &str = "Returns a reference to the value of `{field}`, if it is `Some`.";
+pub const GET_COPY: &str = "Returns a copy of `{field}`.";
+pub const GET_CLONE: &str = "Returns a clone of `{field}`.";
+pub const SET: &str = "Sets `{field}` and returns `&mut Self`.";
+pub const TRY_SET: &str = "Tries to convert the value into `{type}`, sets `{field}` and returns \
+                           `&mut Self`.";
+pub const TRY_VERIFY: &str = "Sets `{field}` and verifies it with the fallible verify function. \
+                              If the verification fails, the previous value is restored and the \
+                              error is returned.";
+pub const GET_MUT: &str = "Returns a mutable reference to `{field}`.";
+pub const TRY_GET_MUT: &str = "Returns a mutable reference to `{field}`, that has to be \
+                               committed.";
+pub const PUSH: &str = "Appends the value to `{field}` and returns `&mut Self`.";
+pub const INSERT: &str = "Inserts the value into `{field}` and returns `&mut Self`.";
+pub const NEW: &str = "Creates a new `{type}`.";
+pub const RESET: &str = "Resets `{field}` to its default value and returns `&mut Self`.";
+pub const INTO_FIELD: &str = "Consumes `Self` and returns `{field}`.";
+pub const SET_FROM_STR: &str =
+    "Parses the string with `FromStr`, sets `{field}` with its setter and returns `&mut Self`.";
+pub const SET_BY_NAME_FROM_STR: &str =
+    "Parses the string and sets the field with the name, like `set_<field>_from_str`.";
+pub const RESET_ALL: &str = "Resets all fields with a default value and returns `&mut Self`.";
+
+/// This note is added to getters with the `must_use` attribute.
+pub const MUST_USE: &str = "This function has no side effects, so ignoring the returned value \
+                            is most likely a mistake.";
+
+/// A part of a documentation template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Part {
+    Text(String),
+    /// `{field}` is replaced with the name of the field.
+    Field,
+    /// `{type}` is replaced with the type of the field.
+    Type,
+}
+
+/// A documentation template, where `{field}` is replaced with the name of the
+/// field and `{type}` with the type of the field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template(Vec<Part>);
+
+impl Template {
+    pub fn new(value: &str, span: Span) -> Result<Self, Error> {
+        let mut parts = vec![];
+        let mut rest = value;
+
+        while let Some(start) = rest.find(['{', '}']) {
+            if rest[start..].starts_with('}') {
+                return Err(Error::custom("unmatched `}` in doc template").with_span(&span));
+            }
+
+            let end = {
+                if let Some(end) = rest[start..].find('}') {
+                    start + end
+                } else {
+                    return Err(Error::custom("unmatched `{` in doc template").with_span(&span));
+                }
+            };
+
+            let placeholder = {
+                match &rest[start + 1..end] {
+                    "field" => Part::Field,
+                    "type" => Part::Type,
+                    placeholder => {
+                        return Err(Error::custom(format!(
+                            "unknown placeholder `{{{placeholder}}}`, expected `{{field}}` or \
+                             `{{type}}`"
+                        ))
+                        .with_span(&span));
+                    }
+                }
+            };
+
+            parts.push(Part::Text(rest[..start].to_string()));
+            parts.push(placeholder);
+            rest = &rest[end + 1..];
+        }
+
+        parts.push(Part::Text(rest.to_string()));
+
+        Ok(Self(parts))
+    }
+
+    pub fn format(&self, field: &Ident, ty: &Type) -> String {
+        self.0
+            .iter()
+            .map(|part| {
+                match part {
+                    Part::Text(text) => text.clone(),
+                    Part::Field => field.to_string(),
+                    Part::Type => type_to_string(ty),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Converts the type to a `String` without the spaces, that are inserted
+/// between the tokens (`Option<String>` instead of `Option < String >`).
+fn type_to_string(ty: &Type) -> String {
+    quote!(#ty)
+        .to_string()
+        .replace(" < ", "<")
+        .replace("< ", "<")
+        .replace(" >", ">")
+        .replace(" :: ", "::")
+        .replace(":: ", "::")
+        .replace(" ,", ",")
+        .replace("& ", "&")
+        .replace("' ", "'")
+}
+
+/// The documentation templates of the derived functions. If there is no
+/// template for a function, the default documentation is used.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Docs {
+    get: Option<Template>,
+    set: Option<Template>,
+    try_set: Option<Template>,
+    get_mut: Option<Template>,
+    try_get_mut: Option<Template>,
+}
+
+impl Docs {
+    /// Replaces the templates with the ones, that are set in `other`.
+    pub fn update(&mut self, other: Self) {
+        self.get = other.get.or_else(|| self.get.take());
+        self.set = other.set.or_else(|| self.set.take());
+        self.try_set = other.try_set.or_else(|| self.try_set.take());
+        self.get_mut = other.get_mut.or_else(|| self.get_mut.take());
+        self.try_get_mut = other.try_get_mut.or_else(|| self.try_get_mut.take());
+    }
+
+    pub const fn get(&self) -> Option<&Template> { self.get.as_ref() }
+
+    pub const fn set(&self) -> Option<&Template> { self.set.as_ref() }
+
+    pub const fn try_set(&self) -> Option<&Template> { self.try_set.as_ref() }
+
+    pub const fn get_mut(&self) -> Option<&Template> { self.get_mut.as_ref() }
+
+    pub const fn try_get_mut(&self) -> Option<&Template> { self.try_get_mut.as_ref() }
+}
+
+impl Parse for Docs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut result = Self::default();
+        let input = parse_shorthand(input)?;
+
+        let mut errors = vec![];
+
+        for meta in input.parse_terminated::<Meta, Token![,]>(Meta::parse)? {
+            // ignore other attributes like `#[shorthand(enable(doc))]`
+            if !meta.path().is_ident("doc") {
+                continue;
+            }
+
+            // #[shorthand(doc(get = "...", set = "..."))]
+            if let Meta::List(list) = &meta {
+                for nested in &list.nested {
+                    let pair = {
+                        match nested {
+                            NestedMeta::Meta(Meta::NameValue(pair)) => pair,
+                            NestedMeta::Meta(meta) => {
+                                errors.push(Error::unexpected_meta(meta).with_alts(["NameValue"]));
+                                continue;
+                            }
+                            NestedMeta::Lit(lit) => {
+                                errors.push(Error::unexpected_lit(lit).with_span(lit));
+                                continue;
+                            }
+                        }
+                    };
+
+                    let template = {
+                        match &pair.lit {
+                            Lit::Str(value) => Template::new(&value.value(), value.span()),
+                            lit => Err(Error::unexpected_lit(lit).with_alts(["string"])),
+                        }
+                    };
+
+                    let template = {
+                        match template {
+                            Ok(value) => Some(value),
+                            Err(e) => {
+                                errors.push(e);
+                                continue;
+                            }
+                        }
+                    };
+
+                    if pair.path.is_ident("get") {
+                        result.get = template;
+                    } else if pair.path.is_ident("set") {
+                        result.set = template;
+                    } else if pair.path.is_ident("try_set") {
+                        result.try_set = template;
+                    } else if pair.path.is_ident("get_mut") {
+                        result.get_mut = template;
+                    } else if pair.path.is_ident("try_get_mut") {
+                        result.try_get_mut = template;
+                    } else {
+                        errors.push(
+                            Error::unknown_field(&pair.path.to_string())
+                                .with_alts(Visibilities::ACCESSORS)
+                                .with_span(&pair.path),
+                        );
+                    }
+                }
+            } else {
+                // #[shorthand(doc)]
+                // #[shorthand(doc = "")]
+                errors.push(Error::unexpected_meta(&meta).with_alts(["List"]));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(result)
+        } else {
+            Err(Error::multiple(errors).into())
+        }
+    }
+}
+
+fn doc_attribute(line: &str) -> Attribute {
+    // `/// line` is the same as `#[doc = " line"]`
+    let line = {
+        if line.is_empty() {
+            String::new()
+        } else {
+            format!(" {line}")
+        }
+    };
+
+    Attribute::from_token_stream(quote!(#[doc = #line])).unwrap()
+}
+
+/// Adds the documentation of a derived function in front of its `attributes`.
+/// The summary is generated from the `template` or the `default` template and
+/// is followed by the `notes`.
+pub fn document(
+    attributes: &mut Vec<Attribute>,
+    template: Option<&Template>,
+    default: &str,
+    field: &Ident,
+    ty: &Type,
+    notes: &[&str],
+) {
+    let summary = template.map_or_else(
+        || {
+            Template::new(default, Span::call_site())
+                .unwrap()
+                .format(field, ty)
+        },
+        |template| template.format(field, ty),
+    );
+
+    let mut paragraphs = vec![summary];
+    paragraphs.extend(notes.iter().map(ToString::to_string));
+
+    prepend(attributes, &paragraphs);
+}
+
+/// Adds the documentation in front of the (forwarded) documentation of the
+/// field, which is separated by an empty line.
+fn prepend(attributes: &mut Vec<Attribute>, paragraphs: &[String]) {
+    let has_doc = attributes.iter().any(|attr| attr.path.is_ident("doc"));
+    let mut result = vec![];
+
+    for (i, paragraph) in paragraphs.iter().enumerate() {
+        if i > 0 {
+            result.push(doc_attribute(""));
+        }
+
+        result.extend(paragraph.lines().map(doc_attribute));
+    }
+
+    if has_doc && !result.is_empty() {
+        result.push(doc_attribute(""));
+    }
+
+    attributes.splice(0..0, result);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_template() {
+        let template = Template::new("Returns `{field}` of type `{type}`.", Span::call_site());
+
+        assert_eq!(
+            template.unwrap().format(
+                &Ident::new("data", Span::call_site()),
+                &syn::parse_str("Option<Vec<&'a str>>").unwrap()
+            ),
+            "Returns `data` of type `Option<Vec<&'a str>>`.".to_string()
+        );
+
+        assert_eq!(
+            Template::new("{fields}", Span::call_site()).unwrap_err(),
+            Error::custom("unknown placeholder `{fields}`, expected `{field}` or `{type}`")
+        );
+        assert!(Template::new("{field", Span::call_site()).is_err());
+        assert!(Template::new("field}", Span::call_site()).is_err());
+    }
+
+    #[test]
+    fn test_parse_docs() {
+        let mut docs = syn::parse2::<Docs>(quote!(#[shorthand(doc(get = "get {field}"))])).unwrap();
+
+        docs.update(syn::parse2(quote!(#[shorthand(doc(set = "set {field}"))])).unwrap());
+
+        assert_eq!(
+            docs.get(),
+            Some(&Template::new("get {field}", Span::call_site()).unwrap())
+        );
+        assert_eq!(
+            docs.set(),
+            Some(&Template::new("set {field}", Span::call_site()).unwrap())
+        );
+        assert_eq!(docs.get_mut(), None);
+
+        assert!(syn::parse2::<Docs>(quote!(#[shorthand(doc(gett = "{field}"))])).is_err());
+    }
+}
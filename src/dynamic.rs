@@ -0,0 +1,130 @@
+//! This module generates `get_dyn` and `set_dyn` for a struct with
+//! `#[shorthand(dynamic)]`, which access the fields by their name. `set_dyn`
+//! calls the setter of the field, so the field is still verified. If the field
+//! has a fallible verify function, `try_set_<field>` is called instead and the
+//! error is returned.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Attribute, Field, WherePredicate};
+
+use crate::error::Error;
+use crate::expand::Generator;
+use crate::options::Options;
+
+/// Returns the `#[cfg(...)]` of a field, which are also needed for the match
+/// arm of the field.
+fn cfg_attributes(options: &Options) -> Vec<&Attribute> {
+    options
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("cfg"))
+        .collect()
+}
+
+/// Generates an `impl` block with `get_dyn` and `set_dyn`, because the types of
+/// the fields have to be `'static` to be converted into `dyn Any`.
+pub fn generate(options: &Options, fields: &[(Options, &Field)]) -> Result<TokenStream, Error> {
+    let name = &options.ident;
+    let vis = &options.vis;
+
+    let error = quote!(::shorthand_runtime::SetError);
+
+    let mut generics = options.generics.clone();
+    let mut get_arms = vec![];
+    let mut set_arms = vec![];
+
+    for (field_options, field) in fields
+        .iter()
+        .filter(|(options, field)| !Generator::is_ignored(options, field))
+    {
+        let attributes = cfg_attributes(field_options);
+        let ident = &field.ident;
+        let ty = &field.ty;
+        let field_name = ident.as_ref().map(ToString::to_string);
+
+        if !(field_options.attributes.get || field_options.attributes.set) {
+            continue;
+        }
+
+        let predicate: WherePredicate = syn::parse2(quote!(#ty: 'static)).unwrap();
+        generics.make_where_clause().predicates.push(predicate);
+
+        if field_options.attributes.get {
+            get_arms.push(quote! {
+                #(#attributes)*
+                #field_name => ::core::option::Option::Some(&self.#ident),
+            });
+        }
+
+        if field_options.attributes.set {
+            // the error of the verify function is converted into `dyn Any`
+            if let Some(error) = field_options.verify.error() {
+                let predicate: WherePredicate = syn::parse2(quote!(#error: 'static)).unwrap();
+                generics.make_where_clause().predicates.push(predicate);
+            }
+
+            let map_error = quote! {
+                |error| #error::Invalid(::std::boxed::Box::new(error))
+            };
+            let application = Generator::call_setter(field_options, field, Some(&map_error))?;
+
+            set_arms.push(quote! {
+                #(#attributes)*
+                #field_name => {
+                    match value.downcast::<#ty>() {
+                        ::core::result::Result::Ok(value) => {
+                            let value = *value;
+                            #application
+                            ::core::result::Result::Ok(())
+                        }
+                        ::core::result::Result::Err(value) => {
+                            ::core::result::Result::Err(#error::WrongType(value))
+                        }
+                    }
+                }
+            });
+        } else {
+            set_arms.push(quote! {
+                #(#attributes)*
+                #field_name => ::core::result::Result::Err(#error::ReadOnly),
+            });
+        }
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        #[allow(dead_code)]
+        #[allow(clippy::all)]
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Returns a reference to the field with the `name`, if it has a
+            /// getter.
+            #[must_use]
+            #vis fn get_dyn(&self, name: &str) -> ::core::option::Option<&dyn ::core::any::Any> {
+                match name {
+                    #(#get_arms)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            /// Sets the field with the `name` to the `value` with its setter.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error, if the struct has no field with the `name`, the
+            /// field has no setter, the `value` does not have the type of the
+            /// field or the fallible verify function rejected the `value`.
+            #vis fn set_dyn(
+                &mut self,
+                name: &str,
+                value: ::std::boxed::Box<dyn ::core::any::Any>,
+            ) -> ::core::result::Result<(), #error> {
+                match name {
+                    #(#set_arms)*
+                    _ => ::core::result::Result::Err(#error::UnknownField),
+                }
+            }
+        }
+    })
+}
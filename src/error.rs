@@ -17,11 +17,11 @@ where
 
     while let Some((i, value)) = iterator.next() {
         if i == 0 {
-            result.push_str(&format!("`{}`", value));
+            result.push_str(&format!("`{value}`"));
         } else if iterator.peek().is_some() {
-            result.push_str(&format!(", `{}`", value));
+            result.push_str(&format!(", `{value}`"));
         } else {
-            result.push_str(&format!(" {} `{}`", last, value));
+            result.push_str(&format!(" {last} `{value}`"));
         }
     }
 
@@ -226,7 +226,7 @@ impl Error {
                 *expected = alts.into_iter().map(|alt| alt.to_string()).collect();
             }
             _ => {}
-        };
+        }
 
         self
     }
@@ -272,10 +272,10 @@ impl fmt::Display for ErrorKind {
                     Ok(())
                 }
             }
-            Self::UnknownField { found, .. } => write!(f, "unknown field `{}`", found),
-            Self::UnexpectedType { found, .. } => write!(f, "unexpected literal type `{}`", found),
+            Self::UnknownField { found, .. } => write!(f, "unknown field `{found}`"),
+            Self::UnexpectedType { found, .. } => write!(f, "unexpected literal type `{found}`"),
             Self::UnexpectedMeta { format, expected } => {
-                write!(f, "unexpected meta-item format `{}`", format)?;
+                write!(f, "unexpected meta-item format `{format}`")?;
 
                 if !expected.is_empty() {
                     write!(f, ", expected {}", format_items(expected, "or"))?;
@@ -283,17 +283,17 @@ impl fmt::Display for ErrorKind {
 
                 Ok(())
             }
-            Self::UnexpectedField { found, .. } => write!(f, "unexpected field `{}`", found),
+            Self::UnexpectedField { found, .. } => write!(f, "unexpected field `{found}`"),
             Self::RedundantField { field, state } => {
-                write!(f, "redundant field `{}`", field)?;
+                write!(f, "redundant field `{field}`")?;
 
                 if let Some(state) = state {
-                    write!(f, ", which is already {}", state)?;
+                    write!(f, ", which is already {state}")?;
                 }
 
                 Ok(())
             }
-            Self::DuplicateField { field } => write!(f, "duplicate field `{}`", field),
+            Self::DuplicateField { field } => write!(f, "duplicate field `{field}`"),
         }
     }
 }
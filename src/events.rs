@@ -0,0 +1,197 @@
+//! This module generates the `ExampleEvent` enum for a struct `Example` with
+//! `#[shorthand(events)]`, which has a variant with the new value for every
+//! field with a setter. Applying an event returns the inverse event, so the
+//! events can be used for an undo history or an event log.
+//!
+//! The setters can record the inverse events with
+//! `#[shorthand(events(record = "Self::record"))]` and traits can be derived
+//! for the enum with `#[shorthand(events(derive(Debug, Clone)))]`.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Attribute, Field, Ident, Lit, Meta, MetaList, NestedMeta, Path};
+
+use crate::error::Error;
+use crate::expand::Generator;
+use crate::options::Options;
+use crate::rename::Rename;
+use crate::utils::PathExt;
+
+pub fn event_ident(name: &Ident) -> Ident { format_ident!("{}Event", name) }
+
+/// Returns the name of the variant of the field, which is the name of the field
+/// in `PascalCase` by default and can be changed with `rename(event = "...")`.
+pub fn variant(options: &Options, field_name: &Ident) -> Result<Ident, Error> {
+    let name = options.strip.apply(field_name)?;
+
+    if options.attributes.rename {
+        Ok(options.rename.format_event(&name))
+    } else {
+        Ok(Rename::default().format_event(&name))
+    }
+}
+
+/// The options of `#[shorthand(events(...))]`.
+#[derive(Debug, Clone, Default)]
+pub struct Events {
+    /// The function, that records the inverse events of the setters.
+    pub record: Option<Path>,
+    /// The traits, that are derived for the event enum.
+    derives: Vec<Path>,
+}
+
+impl Events {
+    /// Parses `events(record = "Self::record", derive(Debug, Clone))`.
+    pub fn update(&mut self, list: &MetaList) -> Result<(), Error> {
+        let mut errors = vec![];
+
+        for nested in &list.nested {
+            match nested {
+                NestedMeta::Meta(Meta::NameValue(pair)) if pair.path.is_ident("record") => {
+                    if let Lit::Str(value) = &pair.lit {
+                        match value.parse::<Path>() {
+                            Ok(path) => self.record = Some(path),
+                            Err(err) => errors.push(Error::syn(err)),
+                        }
+                    } else {
+                        errors.push(Error::unexpected_lit(&pair.lit).with_alts(["string"]));
+                    }
+                }
+                NestedMeta::Meta(Meta::List(derives)) if derives.path.is_ident("derive") => {
+                    for nested in &derives.nested {
+                        if let NestedMeta::Meta(Meta::Path(path)) = nested {
+                            self.derives.push(path.clone());
+                        } else {
+                            errors.push(Error::custom("expected a trait").with_span(nested));
+                        }
+                    }
+                }
+                NestedMeta::Meta(meta) => {
+                    errors.push(
+                        Error::unknown_field(&meta.path().to_string())
+                            .with_alts(["record", "derive"])
+                            .with_span(meta.path()),
+                    );
+                }
+                NestedMeta::Lit(value) => {
+                    errors.push(Error::unexpected_lit(value).with_span(value));
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::multiple(errors))
+        }
+    }
+}
+
+/// Returns the `#[cfg(...)]` of a field, which are also needed, where the
+/// variant of the field is used.
+fn cfg_attributes(options: &Options) -> Vec<&Attribute> {
+    options
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("cfg"))
+        .collect()
+}
+
+/// Generates the `ExampleEvent` enum.
+pub fn generate(options: &Options, fields: &[(Options, &Field)]) -> Result<TokenStream, Error> {
+    let name = &options.ident;
+    let vis = &options.vis;
+    let event = event_ident(name);
+    let (impl_generics, ty_generics, where_clause) = options.generics.split_for_impl();
+
+    let mut variants = vec![];
+    let mut arms = vec![];
+    let mut errors = vec![];
+
+    for (field_options, field) in fields
+        .iter()
+        .filter(|(options, field)| !Generator::is_ignored(options, field) && options.attributes.set)
+    {
+        let ident = &field.ident;
+        let ty = &field.ty;
+        let cfg = cfg_attributes(field_options);
+        let docs = field_options
+            .attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident("doc"));
+
+        let variant = {
+            match variant(field_options, ident.as_ref().unwrap()) {
+                Ok(variant) => variant,
+                Err(err) => {
+                    errors.push(err);
+                    continue;
+                }
+            }
+        };
+
+        let verify = field_options.verify.call_or_panic(&quote!(target));
+
+        variants.push(quote! {
+            #(#cfg)*
+            #(#docs)*
+            #variant(#ty)
+        });
+
+        arms.push(quote! {
+            #(#cfg)*
+            Self::#variant(value) => {
+                let previous = ::core::mem::replace(&mut target.#ident, value);
+                #verify
+                Self::#variant(previous)
+            }
+        });
+    }
+
+    if !errors.is_empty() {
+        return Err(Error::multiple(errors));
+    }
+
+    // the enum has to use all generics, but the fields with a generic could be
+    // skipped
+    if !options.generics.params.is_empty() {
+        variants.push(quote! {
+            #[doc(hidden)]
+            __Marker(
+                ::core::marker::PhantomData<fn() -> #name #ty_generics>,
+                ::core::convert::Infallible,
+            )
+        });
+
+        arms.push(quote! {
+            Self::__Marker(_, never) => match never {},
+        });
+    }
+
+    let doc = format!(
+        "A change of a field of [`{name}`], that has the new value of the field. Applying an event \
+         returns the inverse event, which restores the previous value."
+    );
+
+    let derives = &options.events.derives;
+
+    Ok(quote! {
+        #[doc = #doc]
+        #[derive(#(#derives),*)]
+        #[allow(dead_code)]
+        #vis enum #event #impl_generics #where_clause {
+            #(#variants),*
+        }
+
+        #[allow(dead_code)]
+        impl #impl_generics #event #ty_generics #where_clause {
+            /// Sets the field of `target` to the value of the event and returns the
+            /// inverse event, that has the previous value of the field.
+            #vis fn apply(self, target: &mut #name #ty_generics) -> Self {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+    })
+}
@@ -1,4 +1,4 @@
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::{format_ident, quote, quote_spanned};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned as _;
@@ -7,15 +7,26 @@ use syn::{
     TraitBoundModifier, Type, TypeParamBound, WherePredicate,
 };
 
+use crate::collision;
+use crate::constructor::{self, DefaultValue};
+use crate::doc;
+use crate::dynamic;
 use crate::error::Error;
-use crate::options::Options;
+use crate::events;
+use crate::field_info;
+use crate::guard;
+use crate::options::{Derive, Options};
+use crate::parts;
+use crate::patch;
 use crate::utils::{AttributeExt, PathExt, TypeExt};
+use crate::views;
 
 pub fn derive(input: &DeriveInput) -> crate::Result<TokenStream> {
     let name = &input.ident;
     let mut errors = vec![];
 
     let mut functions: Vec<TokenStream> = vec![];
+    let mut items: Vec<TokenStream> = vec![];
 
     let options = Options::from_derive_input(input)?;
 
@@ -25,21 +36,68 @@ pub fn derive(input: &DeriveInput) -> crate::Result<TokenStream> {
 
             match &s.fields {
                 Fields::Named(named) => {
-                    let result = named
-                        .named
-                        .iter()
-                        .map(|field| generator.generate(field))
-                        .collect::<Vec<Result<_, _>>>();
+                    // the options of each field are needed for the functions of the fields
+                    // and for the items, that are generated for the entire struct.
+                    let fields = collect_results(
+                        named
+                            .named
+                            .iter()
+                            .map(|field| Ok((generator.field_options(field)?, field))),
+                    );
+
+                    let selection = options
+                        .selection
+                        .verify(named.named.iter().filter_map(|field| field.ident.as_ref()));
+
+                    let fields = {
+                        match (fields, selection) {
+                            (Ok(fields), Ok(())) => fields,
+                            (fields, selection) => {
+                                return Err(Error::multiple(
+                                    selection.err().into_iter().chain(fields.err()),
+                                ));
+                            }
+                        }
+                    };
 
-                    if result.iter().any(Result::is_err) {
-                        return Err(Error::multiple(result.into_iter().filter_map(Result::err)));
-                    } else {
-                        functions.extend(
-                            result
-                                .into_iter()
-                                .filter_map(Result::ok)
-                                .collect::<Vec<_>>(),
-                        );
+                    let generated = collect_results(
+                        fields
+                            .iter()
+                            .map(|(options, field)| Generator::generate(options, field)),
+                    )?;
+
+                    let function_names = generated
+                        .iter()
+                        .map(collision::function_names)
+                        .collect::<Vec<_>>();
+
+                    functions.extend(generated);
+
+                    let derived = derive_struct(
+                        &options,
+                        &fields,
+                        &function_names,
+                        &mut functions,
+                        &mut items,
+                    );
+
+                    // rustc would only report a duplicate definition, without the fields
+                    let collisions = collision::check(
+                        &fields
+                            .iter()
+                            .zip(&function_names)
+                            .filter_map(|((_, field), names)| {
+                                Some((field.ident.as_ref()?, names.clone()))
+                            })
+                            .collect::<Vec<_>>(),
+                        derived.as_deref().unwrap_or_default(),
+                        &options.reserved,
+                    );
+
+                    if derived.is_err() || collisions.is_err() {
+                        return Err(Error::multiple(
+                            collisions.err().into_iter().chain(derived.err()),
+                        ));
                     }
                 }
                 // A TupleStruct has no field names.
@@ -65,29 +123,133 @@ pub fn derive(input: &DeriveInput) -> crate::Result<TokenStream> {
         return Err(Error::multiple(errors));
     }
 
-    let (impl_generics, ty_generics, where_clause) = options.generics.split_for_impl();
-
-    if (options.attributes.try_into || options.attributes.into)
-        && quote!(#impl_generics #ty_generics #where_clause)
-            .to_string()
-            .contains("VALUE")
-    {
-        return Err(
-            Error::custom("a generic called `VALUE` is not supported, please rename it.")
-                .with_span(&options.generics),
-        );
+    // proc-macros are not rerun, if a file they read changes, but `include_bytes!`
+    // tells cargo to recompile the crate, if the `shorthand.toml` changes.
+    if let Some(path) = options.config_path.as_ref().and_then(|path| path.to_str()) {
+        items.push(quote! {
+            const _: &[u8] = include_bytes!(#path);
+        });
     }
 
+    let (impl_generics, ty_generics, where_clause) = options.generics.split_for_impl();
+
     Ok(quote! {
         #[allow(dead_code)]
         #[allow(clippy::all)]
         impl #impl_generics #name #ty_generics #where_clause {
             #(#functions)*
         }
+
+        #(#items)*
     })
 }
 
-fn generate_assertion(
+/// Generates the functions and items, that are derived for the entire struct
+/// and not for a single field. Returns the names of the functions and
+/// constants, that are added to the struct, so they can be checked for
+/// collisions with the functions of the fields.
+fn derive_struct(
+    options: &Options,
+    fields: &[(Options, &Field)],
+    names: &[Vec<Ident>],
+    functions: &mut Vec<TokenStream>,
+    items: &mut Vec<TokenStream>,
+) -> Result<Vec<Ident>, Error> {
+    let start = functions.len();
+    let mut derived = vec![];
+
+    if options.derives(Derive::Constructor) {
+        functions.push(constructor::generate(options, fields)?);
+    }
+
+    if fields.iter().any(|(options, field)| {
+        !Generator::is_ignored(options, field)
+            && options.attributes.set
+            && options.default.is_some()
+    }) {
+        functions.push(constructor::reset(options, fields)?);
+    }
+
+    if fields.iter().any(|(options, field)| {
+        !Generator::is_ignored(options, field) && options.attributes.set && options.attributes.parse
+    }) {
+        functions.push(Generator::set_by_name_from_str(options, fields)?);
+    }
+
+    if options.derives(Derive::Default) {
+        items.push(constructor::default_impl(options, fields));
+    }
+
+    if options.derives(Derive::Parts) {
+        functions.push(parts::functions(options, fields));
+        items.push(parts::generate(options, fields));
+    }
+
+    if options.derives(Derive::Views) {
+        functions.push(views::functions(options, fields));
+        items.push(views::generate(options, fields));
+    }
+
+    if options.derives(Derive::Patch) {
+        functions.push(patch::functions(options, fields)?);
+        items.push(patch::generate(options, fields));
+    }
+
+    if options.derives(Derive::Diff) {
+        // `diff` is in its own `impl` block
+        items.push(patch::diff(options, fields));
+        derived.push(Ident::new("diff", Span::call_site()));
+    }
+
+    if options.derives(Derive::Events) {
+        items.push(events::generate(options, fields)?);
+    }
+
+    if options.derives(Derive::FieldInfo) {
+        functions.push(field_info::generate(options, fields, names));
+        derived.push(Ident::new("FIELDS", Span::call_site()));
+    }
+
+    if options.derives(Derive::Dynamic) {
+        // the functions are in their own `impl` block
+        items.push(dynamic::generate(options, fields)?);
+        derived.push(Ident::new("get_dyn", Span::call_site()));
+        derived.push(Ident::new("set_dyn", Span::call_site()));
+    }
+
+    if fields.iter().any(|(options, field)| {
+        !Generator::is_ignored(options, field)
+            && options.attributes.get_mut
+            && options.attributes.guard
+    }) {
+        items.push(guard::generate(options));
+    }
+
+    derived.extend(
+        functions[start..]
+            .iter()
+            .flat_map(collision::function_names),
+    );
+
+    Ok(derived)
+}
+
+/// Collects all `Ok` values or returns all errors, if there is at least one
+/// error.
+fn collect_results<T, I>(iterator: I) -> Result<Vec<T>, Error>
+where
+    I: IntoIterator<Item = Result<T, Error>>,
+{
+    let result = iterator.into_iter().collect::<Vec<Result<_, _>>>();
+
+    if result.iter().any(Result::is_err) {
+        Err(Error::multiple(result.into_iter().filter_map(Result::err)))
+    } else {
+        Ok(result.into_iter().filter_map(Result::ok).collect())
+    }
+}
+
+pub fn generate_assertion(
     name: &TokenStream,
     field_type: &Type,
     generics: &Generics,
@@ -164,38 +326,105 @@ fn generate_assertion(
     }
 }
 
-struct Generator<'a> {
+pub struct Generator<'a> {
     options: &'a Options,
 }
 
 impl<'a> Generator<'a> {
     pub const fn from_options(options: &'a Options) -> Self { Self { options } }
 
+    /// Returns the [`Options`] of the [`Field`], which are the [`Options`] of
+    /// the struct combined with the attributes of the [`Field`].
+    pub fn field_options(&self, field: &Field) -> Result<Options, Error> {
+        self.options.with_attrs(&field.attrs)
+    }
+
+    /// Applies `rename_all` to the name of a function. Functions, that are not
+    /// in snake case, get `#[allow(non_snake_case)]`.
+    fn function_name(
+        options: &Options,
+        function_name: Ident,
+        attributes: &mut Vec<Attribute>,
+    ) -> Ident {
+        let function_name = {
+            match options.rename_all {
+                Some(case) if options.attributes.rename => {
+                    Ident::new(
+                        &case.apply(&function_name.to_string()),
+                        function_name.span(),
+                    )
+                }
+                _ => function_name,
+            }
+        };
+
+        if function_name
+            .to_string()
+            .chars()
+            .any(|c| c.is_ascii_uppercase())
+        {
+            attributes
+                .push(Attribute::from_token_stream(quote!(#[allow(non_snake_case)])).unwrap());
+        }
+
+        function_name
+    }
+
+    /// Returns the generic of setters with `into` or `try_into`, which is
+    /// `VALUE` by default. If the struct already declares a generic `VALUE`,
+    /// underscores are appended until the name is unique.
+    fn value_generic(options: &Options) -> Ident {
+        if let Some(generic) = &options.generic {
+            return generic.clone();
+        }
+
+        let generics = &options.generics;
+        let mut result = "VALUE".to_string();
+
+        while generics.type_params().any(|param| param.ident == result)
+            || generics.const_params().any(|param| param.ident == result)
+        {
+            result.push('_');
+        }
+
+        Ident::new(&result, Span::call_site())
+    }
+
     pub fn get(
         options: &Options,
         field_name: &Ident,
+        name: &Ident,
         field_type: &Type,
     ) -> Result<TokenStream, Error> {
         // apply the rename template, if there is none, use the default:
         // -> field: usize
         // -> with template `prefix_{}_suffix` -> prefix_field_suffix
         // -> without template -> `field`
+        // -> with a template for `bool` fields like `is_{}` -> `is_field`
         let function_name = {
             if options.attributes.rename {
-                options.rename.format_get(field_name)?
+                match options
+                    .rename
+                    .format_get_bool(name, options.bool_prefix.as_ref())
+                {
+                    Some(function_name) if field_type.is_ident("bool") => function_name,
+                    _ => options.rename.format_get(name)?,
+                }
             } else {
-                field_name.clone()
+                name.clone()
             }
         };
 
         let mut attributes: Vec<Attribute> = options.attrs.clone();
+        attributes.extend_from_slice(options.accessor_attrs.get());
+        let function_name = Self::function_name(options, function_name, &mut attributes);
         let arguments = vec![quote![&self]];
-        let visibility = &options.visibility;
+        let visibility = options.visibility.get();
         let mut assertions = vec![];
 
         // add attributes to the function
-        if options.attributes.inline {
-            attributes.push(Attribute::from_token_stream(quote!(#[inline(always)])).unwrap());
+        if let Some(inline) = options.attributes.inline.to_attribute() {
+            attributes.push(inline);
         }
 
         if options.attributes.must_use {
@@ -203,11 +432,11 @@ impl<'a> Generator<'a> {
         }
 
         // change body, depending on the type and config:
-        let (return_type, body) = {
+        let (return_type, body, default_doc) = {
             if options.attributes.primitive_copy && field_type.is_primitive_copy()
                 || options.attributes.copy
             {
-                (quote![#field_type], quote![self.#field_name])
+                (quote![#field_type], quote![self.#field_name], doc::GET_COPY)
             } else if options.attributes.option_as_ref && field_type.is_option() {
                 // The getter will have the following signature
                 // fn field(&self) -> Option<&String>;
@@ -216,6 +445,7 @@ impl<'a> Generator<'a> {
                 (
                     field_type.to_as_ref().unwrap(),
                     quote![self.#field_name.as_ref()],
+                    doc::GET_OPTION,
                 )
             } else if options.attributes.clone {
                 assertions.push(generate_assertion(
@@ -225,12 +455,35 @@ impl<'a> Generator<'a> {
                     &quote!(::std::clone::Clone),
                 ));
 
-                (quote![#field_type], quote! { self.#field_name.clone() })
+                (
+                    quote![#field_type],
+                    quote! { self.#field_name.clone() },
+                    doc::GET_CLONE,
+                )
             } else {
-                (quote![&#field_type], quote![&self.#field_name])
+                (quote![&#field_type], quote![&self.#field_name], doc::GET)
             }
         };
 
+        if options.attributes.doc {
+            let notes: &[&str] = {
+                if options.attributes.must_use {
+                    &[doc::MUST_USE]
+                } else {
+                    &[]
+                }
+            };
+
+            doc::document(
+                &mut attributes,
+                options.docs.get(),
+                default_doc,
+                field_name,
+                field_type,
+                notes,
+            );
+        }
+
         // if the copy field has been enabled an assertion is needed, that ensures, that
         // the type implements `Copy`.
         if options.attributes.copy
@@ -264,39 +517,188 @@ impl<'a> Generator<'a> {
         })
     }
 
-    pub fn set(
-        options: &Options,
-        field_name: &Ident,
-        field_type: &Type,
-    ) -> Result<TokenStream, Error> {
+    /// Returns the name of the setter, before `rename_all` is applied.
+    fn set_name(options: &Options, name: &Ident) -> Result<Ident, Error> {
         // apply the rename template, if there is none, use the default:
         // -> field: usize
         // -> with template `prefix_{}_suffix` -> prefix_field_suffix
         // -> without template -> `set_field`
+        if options.attributes.rename {
+            options.rename.format_set(name)
+        } else {
+            Ok(format_ident!("set_{}", name))
+        }
+    }
+
+    /// Returns the name of the setter of the field, for functions, that call
+    /// the setter.
+    pub fn setter(options: &Options, field_name: &Ident) -> Result<Ident, Error> {
+        let name = options.strip.apply(field_name)?;
+
+        Ok(Self::function_name(
+            options,
+            Self::set_name(options, &name)?,
+            &mut vec![],
+        ))
+    }
+
+    /// Returns the call of the `record` function with the inverse event, that
+    /// has the `previous` value of the field, which is empty, if the events are
+    /// not recorded.
+    fn record(
+        options: &Options,
+        field_name: &Ident,
+        previous: &Ident,
+    ) -> Result<TokenStream, Error> {
+        match &options.events.record {
+            Some(record) if options.derives(Derive::Events) => {
+                let event = events::event_ident(&options.ident);
+                let variant = events::variant(options, field_name)?;
+
+                Ok(quote! {
+                    #record(self, #event::#variant(#previous));
+                })
+            }
+            _ => Ok(quote![]),
+        }
+    }
+
+    /// Returns the assignment of `value` to the field and the call of the
+    /// `record` function with the inverse event, which is empty, if the events
+    /// are not recorded. The event should be recorded after the field has been
+    /// verified.
+    pub fn assign(
+        options: &Options,
+        field_name: &Ident,
+        value: &TokenStream,
+    ) -> Result<(TokenStream, TokenStream), Error> {
+        let previous = format_ident!("__previous_{}", field_name);
+        let record = Self::record(options, field_name, &previous)?;
+
+        if record.is_empty() {
+            Ok((quote! { self.#field_name = #value; }, record))
+        } else {
+            Ok((
+                quote! {
+                    let #previous = ::core::mem::replace(&mut self.#field_name, #value);
+                },
+                record,
+            ))
+        }
+    }
+
+    /// Returns the name of the `try_set_<field>` function of the field, which
+    /// only exists, if the field has a fallible verify function.
+    fn try_setter(options: &Options, field_name: &Ident) -> Result<Ident, Error> {
+        let name = options.strip.apply(field_name)?;
         let function_name = {
             if options.attributes.rename {
-                options.rename.format_set(field_name)?
+                options.rename.format_try_verify(&name)
             } else {
-                format_ident!("set_{}", field_name)
+                format_ident!("try_set_{}", name)
             }
         };
 
-        let mut generics = vec![];
-        let mut arguments = vec![quote![&mut self]];
-        let return_type = quote![&mut Self];
-        let visibility = &options.visibility;
+        Ok(Self::function_name(options, function_name, &mut vec![]))
+    }
+
+    /// Returns the code, that sets the field to `value` with its setter. The
+    /// setter of an `Option` with `strip_option` can not set `None`, so the
+    /// field is assigned directly and verified.
+    ///
+    /// Functions, that return an error, pass `map_error`, which converts the
+    /// error of a fallible verify function into their error. If the field has
+    /// one, `try_set_<field>` is called instead of the setter (which would
+    /// panic) and the error is returned.
+    pub fn call_setter(
+        options: &Options,
+        field: &Field,
+        map_error: Option<&TokenStream>,
+    ) -> Result<TokenStream, Error> {
+        let ident = field.ident.as_ref().unwrap();
+        let map_error = map_error.filter(|_| options.verify.is_fallible());
+
+        let set = {
+            if let Some(map_error) = map_error {
+                let try_setter = Self::try_setter(options, ident)?;
 
+                quote! {
+                    self.#try_setter(value).map_err(#map_error)?;
+                }
+            } else {
+                let setter = Self::setter(options, ident)?;
+
+                quote! {
+                    self.#setter(value);
+                }
+            }
+        };
+
+        if !(options.attributes.strip_option && field.ty.is_ident("Option")) {
+            return Ok(set);
+        }
+
+        let none = quote!(::core::option::Option::None);
+
+        let unset = {
+            if let (Some(map_error), Some(try_call)) =
+                (map_error, options.verify.try_call(&quote!(&*self)))
+            {
+                let previous = format_ident!("__previous_{}", ident);
+                let record = Self::record(options, ident, &previous)?;
+
+                quote! {
+                    let #previous = ::core::mem::replace(&mut self.#ident, #none);
+
+                    if let ::core::result::Result::Err(error) = #try_call {
+                        self.#ident = #previous;
+                        return ::core::result::Result::Err((#map_error)(error));
+                    }
+
+                    #record
+                }
+            } else {
+                let verify = &options.verify;
+                let (assignment, record) = Self::assign(options, ident, &none)?;
+
+                quote! {
+                    #assignment
+                    #verify
+                    #record
+                }
+            }
+        };
+
+        Ok(quote! {
+            match value {
+                ::core::option::Option::Some(value) => {
+                    #set
+                }
+                ::core::option::Option::None => {
+                    #unset
+                }
+            }
+        })
+    }
+
+    /// Returns the bounds of the generics, the argument and the value of a
+    /// setter, which depend on `into` and `strip_option`.
+    fn set_argument(
+        options: &Options,
+        field_type: &Type,
+    ) -> (Vec<TokenStream>, TokenStream, TokenStream) {
+        let mut generics = vec![];
         let mut argument = quote! { value: #field_type };
-        let mut assignment = quote! { self.#field_name = value; };
+        let mut value = quote! { value };
 
         if options.attributes.into {
-            argument = quote! { value: VALUE };
-            let mut bound = quote! { VALUE: ::std::convert::Into<#field_type> };
+            let generic = Self::value_generic(options);
+
+            argument = quote! { value: #generic };
+            let mut bound = quote! { #generic: ::std::convert::Into<#field_type> };
 
             // default assignment for into
-            assignment = quote! {
-                self.#field_name = value.into();
-            };
+            value = quote! { value.into() };
 
             // For Option we might want to have
             //
@@ -313,18 +715,14 @@ impl<'a> Generator<'a> {
                     .into_iter()
                     .find_map(|s| s.into_iter().last())
                 {
-                    bound = quote! { VALUE: ::std::convert::Into<#arg> };
+                    bound = quote! { #generic: ::std::convert::Into<#arg> };
 
                     if options.attributes.strip_option {
-                        assignment = quote! {
-                            self.#field_name = Some(value.into());
-                        };
+                        value = quote! { Some(value.into()) };
                     } else {
-                        argument = quote! { value: ::std::option::Option<VALUE> };
+                        argument = quote! { value: ::std::option::Option<#generic> };
 
-                        assignment = quote! {
-                            self.#field_name = value.map(|v| v.into());
-                        };
+                        value = quote! { value.map(|v| v.into()) };
                     }
                 }
             }
@@ -338,19 +736,46 @@ impl<'a> Generator<'a> {
             {
                 argument = quote! { value: #arg };
 
-                assignment = quote! {
-                    self.#field_name = Some(value);
-                };
+                value = quote! { Some(value) };
             }
         }
 
+        (generics, argument, value)
+    }
+
+    pub fn set(
+        options: &Options,
+        field_name: &Ident,
+        name: &Ident,
+        field_type: &Type,
+    ) -> Result<TokenStream, Error> {
+        let function_name = Self::set_name(options, name)?;
+
+        let mut arguments = vec![quote![&mut self]];
+        let return_type = quote![&mut Self];
+        let visibility = options.visibility.set();
+
+        let (generics, argument, value) = Self::set_argument(options, field_type);
         arguments.push(argument);
 
         // Attributes like `#[allow(clippy::use_self)]`
         let mut attributes: Vec<Attribute> = options.attrs.clone();
+        attributes.extend_from_slice(options.accessor_attrs.set());
+        let function_name = Self::function_name(options, function_name, &mut attributes);
 
-        if options.attributes.inline {
-            attributes.push(Attribute::from_token_stream(quote!(#[inline(always)])).unwrap());
+        if let Some(inline) = options.attributes.inline.to_attribute() {
+            attributes.push(inline);
+        }
+
+        if options.attributes.doc {
+            doc::document(
+                &mut attributes,
+                options.docs.set(),
+                doc::SET,
+                field_name,
+                field_type,
+                &[],
+            );
         }
 
         // TODO: allow const for setters
@@ -359,43 +784,378 @@ impl<'a> Generator<'a> {
 
         let verify = &options.verify;
 
+        // the setter records the inverse event after the field has been verified
+        let (assignment, record) = Self::assign(options, field_name, &value)?;
+
         Ok(quote! {
             #(#attributes)*
             #visibility fn #function_name <#(#generics),*> ( #(#arguments),* ) -> #return_type {
                 #assignment
                 #verify
+                #record
                 self
             }
         })
     }
 
-    pub fn try_set(
+    /// Generates `try_set_<field>`, which sets the field like the setter, but
+    /// calls the `try_fn` of `verify` and restores the previous value, if it
+    /// fails.
+    pub fn try_verify(
+        options: &Options,
+        field_name: &Ident,
+        name: &Ident,
+        field_type: &Type,
+    ) -> Result<TokenStream, Error> {
+        let (try_call, error) = {
+            if let (Some(try_call), Some(error)) = (
+                options.verify.try_call(&quote!(&*self)),
+                options.verify.error(),
+            ) {
+                (try_call, error)
+            } else {
+                return Ok(quote![]);
+            }
+        };
+
+        let function_name = {
+            if options.attributes.rename {
+                options.rename.format_try_verify(name)
+            } else {
+                format_ident!("try_set_{}", name)
+            }
+        };
+
+        let mut attributes: Vec<Attribute> = options.attrs.clone();
+        attributes.extend_from_slice(options.accessor_attrs.set());
+        let function_name = Self::function_name(options, function_name, &mut attributes);
+        let visibility = options.visibility.set();
+
+        if let Some(inline) = options.attributes.inline.to_attribute() {
+            attributes.push(inline);
+        }
+
+        if options.attributes.doc {
+            doc::document(
+                &mut attributes,
+                None,
+                doc::TRY_VERIFY,
+                field_name,
+                field_type,
+                &[],
+            );
+        }
+
+        let (generics, argument, value) = Self::set_argument(options, field_type);
+        let previous = format_ident!("__previous_{}", field_name);
+        let record = Self::record(options, field_name, &previous)?;
+
+        Ok(quote! {
+            #(#attributes)*
+            #visibility fn #function_name<#(#generics),*>(
+                &mut self,
+                #argument
+            ) -> ::core::result::Result<&mut Self, #error> {
+                let #previous = ::core::mem::replace(&mut self.#field_name, #value);
+
+                if let ::core::result::Result::Err(error) = #try_call {
+                    self.#field_name = #previous;
+                    return ::core::result::Result::Err(error);
+                }
+
+                #record
+                ::core::result::Result::Ok(self)
+            }
+        })
+    }
+
+    pub fn reset(
         options: &Options,
         field_name: &Ident,
+        name: &Ident,
         field_type: &Type,
+        default: &DefaultValue,
     ) -> Result<TokenStream, Error> {
         let function_name = {
             if options.attributes.rename {
-                options.rename.format_try_set(field_name)?
+                options.rename.format_reset(name)
             } else {
-                format_ident!("try_{}", field_name)
+                format_ident!("reset_{}", name)
             }
         };
 
         let mut attributes: Vec<Attribute> = options.attrs.clone();
-        let mut argument = quote! { value: VALUE };
+        attributes.extend_from_slice(options.accessor_attrs.set());
+        let function_name = Self::function_name(options, function_name, &mut attributes);
+        let visibility = options.visibility.set();
+        let assertion = default.assertion(field_type, &options.generics);
 
-        if options.attributes.inline {
-            attributes.push(Attribute::from_token_stream(quote!(#[inline(always)])).unwrap());
+        if let Some(inline) = options.attributes.inline.to_attribute() {
+            attributes.push(inline);
         }
-        let visibility = &options.visibility;
 
-        let mut body = quote! {
-            self.#field_name = value.try_into()?;
+        if options.attributes.doc {
+            doc::document(
+                &mut attributes,
+                None,
+                doc::RESET,
+                field_name,
+                field_type,
+                &[],
+            );
+        }
+
+        let verify = &options.verify;
+        let (assignment, record) = Self::assign(options, field_name, &quote!(#default))?;
+
+        Ok(quote! {
+            #(#attributes)*
+            #visibility fn #function_name(&mut self) -> &mut Self {
+                #assertion
+                #assignment
+                #verify
+                #record
+                self
+            }
+        })
+    }
+
+    pub fn into_field(
+        options: &Options,
+        field_name: &Ident,
+        name: &Ident,
+        field_type: &Type,
+    ) -> TokenStream {
+        let function_name = {
+            if options.attributes.rename {
+                options.rename.format_into_field(name)
+            } else {
+                format_ident!("into_{}", name)
+            }
         };
 
+        let mut attributes: Vec<Attribute> = options.attrs.clone();
+        attributes.extend_from_slice(options.accessor_attrs.get());
+        let function_name = Self::function_name(options, function_name, &mut attributes);
+        let visibility = options.visibility.get();
+
+        if let Some(inline) = options.attributes.inline.to_attribute() {
+            attributes.push(inline);
+        }
+
+        if options.attributes.must_use {
+            attributes.push(Attribute::from_token_stream(quote!(#[must_use])).unwrap());
+        }
+
+        if options.attributes.doc {
+            doc::document(
+                &mut attributes,
+                None,
+                doc::INTO_FIELD,
+                field_name,
+                field_type,
+                &[],
+            );
+        }
+
+        quote! {
+            #(#attributes)*
+            #visibility fn #function_name(self) -> #field_type {
+                self.#field_name
+            }
+        }
+    }
+
+    /// Returns the name of the `set_<field>_from_str` function of the field.
+    fn set_from_str_name(options: &Options, field_name: &Ident) -> Result<Ident, Error> {
+        let name = options.strip.apply(field_name)?;
+        let function_name = {
+            if options.attributes.rename {
+                options.rename.format_parse(&name)
+            } else {
+                format_ident!("set_{}_from_str", name)
+            }
+        };
+
+        Ok(Self::function_name(options, function_name, &mut vec![]))
+    }
+
+    pub fn set_from_str(options: &Options, field: &Field) -> Result<TokenStream, Error> {
+        let field_name = field.ident.as_ref().unwrap();
+        let field_type = &field.ty;
+
+        let mut attributes: Vec<Attribute> = options.attrs.clone();
+        attributes.extend_from_slice(options.accessor_attrs.set());
+        let function_name = Self::set_from_str_name(options, field_name)?;
+        let visibility = options.visibility.set();
+
+        if let Some(inline) = options.attributes.inline.to_attribute() {
+            attributes.push(inline);
+        }
+
+        if options.attributes.doc {
+            doc::document(
+                &mut attributes,
+                None,
+                doc::SET_FROM_STR,
+                field_name,
+                field_type,
+                &[],
+            );
+        }
+
+        // an `Option<T>` is set to the parsed `T`
+        let option_arg = {
+            if field_type.is_ident("Option") {
+                field_type
+                    .arguments()
+                    .into_iter()
+                    .find_map(|s| s.into_iter().last())
+            } else {
+                None
+            }
+        };
+
+        let (parsed_type, value) = option_arg.map_or_else(
+            || (quote![#field_type], quote![value]),
+            |arg| (quote![#arg], quote![::core::option::Option::Some(value)]),
+        );
+
+        let name = field_name.to_string();
+        let error = quote![::shorthand_runtime::FieldParseError];
+        let map_error = quote! {
+            |error| #error::Invalid {
+                field: #name,
+                message: ::std::string::ToString::to_string(&error),
+            }
+        };
+        let application = Self::call_setter(options, field, Some(&map_error))?;
+
+        Ok(quote! {
+            #(#attributes)*
+            #visibility fn #function_name(
+                &mut self,
+                s: &str,
+            ) -> ::core::result::Result<&mut Self, #error> {
+                let value = <#parsed_type as ::core::str::FromStr>::from_str(s).map_err(|error| {
+                    #error::Invalid {
+                        field: #name,
+                        message: ::std::string::ToString::to_string(&error),
+                    }
+                })?;
+                let value = #value;
+                #application
+                ::core::result::Result::Ok(self)
+            }
+        })
+    }
+
+    /// Generates `set_by_name_from_str`, which calls the `set_<field>_from_str`
+    /// function of the field with the name.
+    pub fn set_by_name_from_str(
+        options: &Options,
+        fields: &[(Options, &Field)],
+    ) -> Result<TokenStream, Error> {
+        let mut arms = vec![];
+
+        for (field_options, field) in fields.iter().filter(|(options, field)| {
+            !Self::is_ignored(options, field) && options.attributes.set && options.attributes.parse
+        }) {
+            let field_name = field.ident.as_ref().unwrap();
+            let name = field_name.to_string();
+            let function_name = Self::set_from_str_name(field_options, field_name)?;
+            let cfg = field_options
+                .attrs
+                .iter()
+                .filter(|attr| attr.path.is_ident("cfg"));
+
+            arms.push(quote! {
+                #(#cfg)*
+                #name => self.#function_name(s),
+            });
+        }
+
+        let mut attributes: Vec<Attribute> = vec![];
+
+        if let Some(inline) = options.attributes.inline.to_attribute() {
+            attributes.push(inline);
+        }
+
+        if options.attributes.doc {
+            let ident = &options.ident;
+            let ty: Type = syn::parse2(quote!(#ident)).unwrap();
+
+            doc::document(
+                &mut attributes,
+                None,
+                doc::SET_BY_NAME_FROM_STR,
+                ident,
+                &ty,
+                &[],
+            );
+        }
+
+        let visibility = options.visibility.set();
+        let error = quote![::shorthand_runtime::FieldParseError];
+
+        Ok(quote! {
+            #(#attributes)*
+            #visibility fn set_by_name_from_str(
+                &mut self,
+                name: &str,
+                s: &str,
+            ) -> ::core::result::Result<&mut Self, #error> {
+                match name {
+                    #(#arms)*
+                    _ => ::core::result::Result::Err(
+                        #error::UnknownField(::std::string::ToString::to_string(name))
+                    ),
+                }
+            }
+        })
+    }
+
+    pub fn try_set(
+        options: &Options,
+        field_name: &Ident,
+        name: &Ident,
+        field_type: &Type,
+    ) -> Result<TokenStream, Error> {
+        let function_name = {
+            if options.attributes.rename {
+                options.rename.format_try_set(name)?
+            } else {
+                format_ident!("try_{}", name)
+            }
+        };
+
+        let mut attributes: Vec<Attribute> = options.attrs.clone();
+        attributes.extend_from_slice(options.accessor_attrs.try_set());
+        let function_name = Self::function_name(options, function_name, &mut attributes);
+        let generic = Self::value_generic(options);
+        let mut argument = quote! { value: #generic };
+
+        if let Some(inline) = options.attributes.inline.to_attribute() {
+            attributes.push(inline);
+        }
+
+        if options.attributes.doc {
+            doc::document(
+                &mut attributes,
+                options.docs.try_set(),
+                doc::TRY_SET,
+                field_name,
+                field_type,
+                &[],
+            );
+        }
+
+        let visibility = options.visibility.try_set();
+
+        let mut value = quote! { value.try_into()? };
+
         let mut bound = quote! {
-            VALUE: ::std::convert::TryInto<#field_type>
+            #generic: ::std::convert::TryInto<#field_type>
         };
 
         if field_type.is_ident("Option") {
@@ -405,35 +1165,33 @@ impl<'a> Generator<'a> {
                 .find_map(|s| s.into_iter().last())
             {
                 if options.attributes.strip_option {
-                    body = quote! {
-                        self.#field_name = Some(value.try_into()?);
-                    };
+                    value = quote! { Some(value.try_into()?) };
                 } else {
-                    argument = quote! { value: ::std::option::Option<VALUE> };
-                    body = quote! {
-                        self.#field_name = value.map(|v| v.try_into()).transpose()?;
-                    };
+                    argument = quote! { value: ::std::option::Option<#generic> };
+                    value = quote! { value.map(|v| v.try_into()).transpose()? };
                 }
 
                 bound = quote! {
-                    VALUE: ::std::convert::TryInto<#arg>
+                    #generic: ::std::convert::TryInto<#arg>
                 };
             }
         }
 
         let verify = &options.verify;
+        let (assignment, record) = Self::assign(options, field_name, &value)?;
 
         Ok(quote! {
             #(#attributes)*
-            #visibility fn #function_name<VALUE>(
+            #visibility fn #function_name<#generic>(
                 &mut self,
                 #argument
-            ) -> Result<&mut Self, VALUE::Error>
+            ) -> Result<&mut Self, #generic::Error>
             where
                 #bound
             {
-                #body
+                #assignment
                 #verify
+                #record
                 Ok(self)
             }
         })
@@ -442,27 +1200,62 @@ impl<'a> Generator<'a> {
     pub fn get_mut(
         options: &Options,
         field_name: &Ident,
+        name: &Ident,
         field_type: &Type,
     ) -> Result<TokenStream, Error> {
         let function_name = {
             if options.attributes.rename {
-                options.rename.format_get_mut(field_name)?
+                options.rename.format_get_mut(name)?
             } else {
-                format_ident!("{}_mut", field_name)
+                format_ident!("{}_mut", name)
             }
         };
 
         let mut attributes: Vec<Attribute> = options.attrs.clone();
+        attributes.extend_from_slice(options.accessor_attrs.get_mut());
+        let function_name = Self::function_name(options, function_name, &mut attributes);
 
-        if options.attributes.inline {
-            attributes.push(Attribute::from_token_stream(quote!(#[inline(always)])).unwrap());
+        if let Some(inline) = options.attributes.inline.to_attribute() {
+            attributes.push(inline);
         }
 
         if options.attributes.must_use {
             attributes.push(Attribute::from_token_stream(quote!(#[must_use])).unwrap());
         }
 
-        let visibility = &options.visibility;
+        if options.attributes.doc {
+            doc::document(
+                &mut attributes,
+                options.docs.get_mut(),
+                doc::GET_MUT,
+                field_name,
+                field_type,
+                &[],
+            );
+        }
+
+        let visibility = options.visibility.get_mut();
+
+        if options.attributes.guard {
+            let guard = guard::guard_ident(&options.ident);
+            let arguments = guard::guard_arguments(&options.generics, &[quote!(#field_type)]);
+            let verify = options
+                .verify
+                .call_or_panic(&quote!(this))
+                .map_or_else(|| quote!(|_| {}), |call| quote!(|this| { #call }));
+
+            return Ok(quote! {
+                #(#attributes)*
+                #visibility fn #function_name(&mut self) -> #guard<#(#arguments),*> {
+                    #guard {
+                        inner: self,
+                        get: |this| &this.#field_name,
+                        get_mut: |this| &mut this.#field_name,
+                        verify: #verify,
+                    }
+                }
+            });
+        }
 
         Ok(quote! {
             #(#attributes)*
@@ -472,12 +1265,82 @@ impl<'a> Generator<'a> {
         })
     }
 
-    pub fn collection_magic(
+    pub fn try_get_mut(
         options: &Options,
         field_name: &Ident,
+        name: &Ident,
         field_type: &Type,
     ) -> Result<TokenStream, Error> {
-        let visibility = &options.visibility;
+        let (try_verify, error) = {
+            if let (Some(try_verify), Some(error)) = (
+                options.verify.try_call(&quote!(this)),
+                options.verify.error(),
+            ) {
+                (try_verify, error)
+            } else {
+                return Ok(quote![]);
+            }
+        };
+
+        let function_name = {
+            if options.attributes.rename {
+                options.rename.format_try_get_mut(name)?
+            } else {
+                format_ident!("try_{}_mut", name)
+            }
+        };
+
+        let mut attributes: Vec<Attribute> = options.attrs.clone();
+        attributes.extend_from_slice(options.accessor_attrs.try_get_mut());
+        let function_name = Self::function_name(options, function_name, &mut attributes);
+
+        if let Some(inline) = options.attributes.inline.to_attribute() {
+            attributes.push(inline);
+        }
+
+        attributes.push(Attribute::from_token_stream(quote!(#[must_use])).unwrap());
+
+        if options.attributes.doc {
+            doc::document(
+                &mut attributes,
+                options.docs.try_get_mut(),
+                doc::TRY_GET_MUT,
+                field_name,
+                field_type,
+                &[],
+            );
+        }
+
+        let visibility = options.visibility.try_get_mut();
+        let try_guard = guard::try_guard_ident(&options.ident);
+        let arguments =
+            guard::guard_arguments(&options.generics, &[quote!(#field_type), quote!(#error)]);
+
+        Ok(quote! {
+            #(#attributes)*
+            #visibility fn #function_name(
+                &mut self
+            ) -> #try_guard<#(#arguments),*> {
+                #try_guard {
+                    original: ::core::option::Option::Some(
+                        ::core::clone::Clone::clone(&self.#field_name)
+                    ),
+                    inner: self,
+                    get: |this| &this.#field_name,
+                    get_mut: |this| &mut this.#field_name,
+                    verify: |this| #try_verify,
+                }
+            }
+        })
+    }
+
+    pub fn collection_magic(
+        options: &Options,
+        field_name: &Ident,
+        name: &Ident,
+        field_type: &Type,
+    ) -> TokenStream {
+        let visibility = options.visibility.set();
         let mut attributes = options.attrs.clone();
 
         let type_name = Ident::new(&field_type.path().unwrap().to_string(), field_type.span());
@@ -491,8 +1354,8 @@ impl<'a> Generator<'a> {
             arguments.push(quote!(#ident: #value));
         }
 
-        if options.attributes.inline {
-            attributes.push(Attribute::from_token_stream(quote!(#[inline(always)])).unwrap());
+        if let Some(inline) = options.attributes.inline.to_attribute() {
+            attributes.push(inline);
         }
 
         if options.attributes.must_use {
@@ -500,7 +1363,13 @@ impl<'a> Generator<'a> {
         }
 
         if field_type.is_ident("Vec") {
-            function_name = Some(format_ident!("push_{}", field_name));
+            function_name = Some({
+                if options.attributes.rename {
+                    options.rename.format_push(name)
+                } else {
+                    format_ident!("push_{}", name)
+                }
+            });
             body = quote_spanned! {
                 field_type.span() =>
                 struct __AssertVec(::std::vec::Vec<()>);
@@ -516,7 +1385,13 @@ impl<'a> Generator<'a> {
             let insert_args = (0..arguments.len() - 1).map(|i| format_ident!("value_{}", i));
             let assert_args = (0..arguments.len() - 1).map(|_| quote![()]);
 
-            function_name = Some(format_ident!("insert_{}", field_name));
+            function_name = Some({
+                if options.attributes.rename {
+                    options.rename.format_insert(name)
+                } else {
+                    format_ident!("insert_{}", name)
+                }
+            });
             body = quote_spanned! {
                 field_type.span() =>
                 struct __AssertCollection(::std::collections::#type_name<#(#assert_args),*>);
@@ -527,42 +1402,45 @@ impl<'a> Generator<'a> {
         }
 
         if let Some(function_name) = function_name {
-            Ok(quote! {
+            let function_name = Self::function_name(options, function_name, &mut attributes);
+
+            if options.attributes.doc {
+                let default_doc = {
+                    if field_type.is_ident("Vec") {
+                        doc::PUSH
+                    } else {
+                        doc::INSERT
+                    }
+                };
+
+                doc::document(
+                    &mut attributes,
+                    None,
+                    default_doc,
+                    field_name,
+                    field_type,
+                    &[],
+                );
+            }
+
+            quote! {
                 #(#attributes)*
                 #visibility fn #function_name(#(#arguments),*) -> &mut Self {
                     #body
                 }
-            })
+            }
         } else {
-            Ok(quote!())
+            quote!()
         }
     }
 
-    /// This function generates the Functions for a [`Field`], based on the
-    /// [`Options`] and the [`Field`] itself.
-    pub fn generate(&self, field: &Field) -> Result<TokenStream, Error> {
-        let options = self.options.with_attrs(&field.attrs)?;
-
-        let field_name = {
-            if let Some(ident) = field.ident.as_ref() {
-                ident
-            } else {
-                // This shouldn't be reached, because expand::derive ensures, that all fields
-                // have a name.
-                unreachable!("unnamed field guard failed");
-            }
-        };
-
-        let mut result = quote![];
-
-        if (options.attributes.ignore_phantomdata && field.ty.is_ident("PhantomData"))
+    /// Returns `true`, if no functions should be generated for the [`Field`].
+    pub fn is_ignored(options: &Options, field: &Field) -> bool {
+        (options.attributes.ignore_phantomdata && field.ty.is_ident("PhantomData"))
             || options.attributes.skip
-            || (options.attributes.ignore_underscore && {
-                field
-                    .ty
-                    .path()
-                    .map_or(false, |p| p.to_string().starts_with('_'))
-            })
+            || matches!(&field.ident, Some(ident) if options.selection.is_excluded(ident))
+            || (options.attributes.ignore_underscore
+                && matches!(&field.ident, Some(ident) if ident.to_string().starts_with('_')))
             || {
                 // empty tuple
                 if let syn::Type::Tuple(s) = &field.ty {
@@ -578,12 +1456,33 @@ impl<'a> Generator<'a> {
                     false
                 }
             }
-        {
+    }
+
+    /// This function generates the Functions for a [`Field`], based on the
+    /// [`Options`] of the [`Field`] and the [`Field`] itself.
+    pub fn generate(options: &Options, field: &Field) -> Result<TokenStream, Error> {
+        let field_name = {
+            if let Some(ident) = field.ident.as_ref() {
+                ident
+            } else {
+                // This shouldn't be reached, because expand::derive ensures, that all fields
+                // have a name.
+                unreachable!("unnamed field guard failed");
+            }
+        };
+
+        let mut result = quote![];
+
+        if Self::is_ignored(options, field) {
             return Ok(quote![]);
         }
 
+        // the name of the field without the prefix and suffix, that should be stripped,
+        // which is used for the names of the functions
+        let name = options.strip.apply(field_name)?;
+
         if options.attributes.get {
-            let function = Self::get(&options, field_name, &field.ty)?;
+            let function = Self::get(options, field_name, &name, &field.ty)?;
             result = quote! {
                 #result
                 #function
@@ -591,7 +1490,37 @@ impl<'a> Generator<'a> {
         }
 
         if options.attributes.set {
-            let function = Self::set(&options, field_name, &field.ty)?;
+            let function = Self::set(options, field_name, &name, &field.ty)?;
+            result = quote! {
+                #result
+                #function
+            };
+
+            let function = Self::try_verify(options, field_name, &name, &field.ty)?;
+            result = quote! {
+                #result
+                #function
+            };
+        }
+
+        if let (true, Some(default)) = (options.attributes.set, &options.default) {
+            let function = Self::reset(options, field_name, &name, &field.ty, default)?;
+            result = quote! {
+                #result
+                #function
+            };
+        }
+
+        if options.attributes.into_field {
+            let function = Self::into_field(options, field_name, &name, &field.ty);
+            result = quote! {
+                #result
+                #function
+            };
+        }
+
+        if options.attributes.set && options.attributes.parse {
+            let function = Self::set_from_str(options, field)?;
             result = quote! {
                 #result
                 #function
@@ -599,7 +1528,7 @@ impl<'a> Generator<'a> {
         }
 
         if options.attributes.try_into {
-            let function = Self::try_set(&options, field_name, &field.ty)?;
+            let function = Self::try_set(options, field_name, &name, &field.ty)?;
             result = quote! {
                 #result
                 #function
@@ -607,11 +1536,19 @@ impl<'a> Generator<'a> {
         }
 
         if options.attributes.get_mut {
-            let function = Self::get_mut(&options, field_name, &field.ty)?;
+            let function = Self::get_mut(options, field_name, &name, &field.ty)?;
             result = quote! {
                 #result
                 #function
             };
+
+            if options.attributes.guard {
+                let function = Self::try_get_mut(options, field_name, &name, &field.ty)?;
+                result = quote! {
+                    #result
+                    #function
+                };
+            }
         }
 
         #[allow(clippy::collapsible_if)]
@@ -623,7 +1560,7 @@ impl<'a> Generator<'a> {
                     || field.ty.is_ident("HashMap")
                     || field.ty.is_ident("HashSet")
                 {
-                    let function = Self::collection_magic(&options, field_name, &field.ty)?;
+                    let function = Self::collection_magic(options, field_name, &name, &field.ty);
                     result = quote! {
                         #result
                         #function
@@ -631,6 +1568,32 @@ impl<'a> Generator<'a> {
                 }
             }
         }
+
+        Self::verify_names(field_name, &result)?;
+
         Ok(result)
     }
+
+    /// The stripped name of a field can be a keyword like `type`, which is only
+    /// valid as a part of the name of a function (`set_type`), so the names of
+    /// the functions are validated, after they have been formatted.
+    fn verify_names(field_name: &Ident, functions: &TokenStream) -> Result<(), Error> {
+        let errors = collision::function_names(functions)
+            .into_iter()
+            .filter(|name| syn::parse_str::<Ident>(&name.to_string()).is_err())
+            .map(|name| {
+                Error::custom(format!(
+                    "the name `{name}` of the function, that is derived for the field `{field_name}`, is not \
+                     a valid identifier"
+                ))
+                .with_span(field_name)
+            })
+            .collect::<Vec<_>>();
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::multiple(errors))
+        }
+    }
 }
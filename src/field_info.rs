@@ -0,0 +1,98 @@
+//! This module generates the associated constant `Example::FIELDS` for a
+//! struct `Example` with `#[shorthand(field_info)]`, which describes every
+//! field with a `FieldInfo` from the `shorthand_runtime` crate.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Field, Ident, Type};
+
+use crate::expand::Generator;
+use crate::options::Options;
+use crate::utils::TypeExt;
+
+/// Returns the type as it would be written in the source code, because the
+/// `TokenStream` adds spaces between all tokens (`Vec < u8 >`).
+fn type_name(ty: &Type) -> String {
+    quote!(#ty)
+        .to_string()
+        .replace(" :: ", "::")
+        .replace(":: ", "::")
+        .replace(" <", "<")
+        .replace("< ", "<")
+        .replace(" >", ">")
+        .replace(" ,", ",")
+        .replace(" ;", ";")
+        .replace("& ", "&")
+}
+
+/// Returns `true`, if the getter of the field returns a copy.
+fn is_copy(options: &Options, field: &Field) -> bool {
+    options.attributes.get
+        && (options.attributes.copy
+            || (options.attributes.primitive_copy && field.ty.is_primitive_copy()))
+}
+
+/// Generates `FIELDS`, where `names` are the names of the functions, that have
+/// been derived for each field.
+pub fn generate(
+    options: &Options,
+    fields: &[(Options, &Field)],
+    names: &[Vec<Ident>],
+) -> TokenStream {
+    let vis = &options.vis;
+
+    let infos = fields
+        .iter()
+        .zip(names)
+        .map(|((field_options, field), names)| {
+            let name = field.ident.as_ref().map(ToString::to_string);
+            let type_name = type_name(&field.ty);
+            let accessors = names.iter().map(ToString::to_string);
+
+            let skipped = Generator::is_ignored(field_options, field);
+            let read_only = skipped
+                || !(field_options.attributes.set
+                    || field_options.attributes.get_mut
+                    || field_options.attributes.try_into);
+            let copy = !skipped && is_copy(field_options, field);
+
+            quote! {
+                ::shorthand_runtime::FieldInfo {
+                    name: #name,
+                    type_name: #type_name,
+                    accessors: &[#(#accessors),*],
+                    read_only: #read_only,
+                    skipped: #skipped,
+                    copy: #copy,
+                }
+            }
+        });
+
+    quote! {
+        /// Information about all fields of the struct, in the order of their
+        /// declaration.
+        #vis const FIELDS: &'static [::shorthand_runtime::FieldInfo] = &[#(#infos),*];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_type_name() {
+        let ty: Type = syn::parse_str("Vec<u8>").unwrap();
+        assert_eq!(type_name(&ty), "Vec<u8>".to_string());
+
+        let ty: Type =
+            syn::parse_str("::std::collections::HashMap<String, Option<&'a str>>").unwrap();
+        assert_eq!(
+            type_name(&ty),
+            "::std::collections::HashMap<String, Option<&'a str>>".to_string()
+        );
+
+        let ty: Type = syn::parse_str("[u8; 4]").unwrap();
+        assert_eq!(type_name(&ty), "[u8; 4]".to_string());
+    }
+}
@@ -1,12 +1,14 @@
 use core::fmt;
 use std::collections::HashMap;
 
+use quote::quote;
 use syn::parse::{Parse, ParseStream};
-use syn::{Meta, NestedMeta, Token};
+use syn::{Lit, Meta, NestedMeta, Token};
 
 use crate::error::Error;
 use crate::parser::{parse_enable_disable, parse_shorthand};
-use crate::utils::PathExt;
+use crate::utils::{AttributeExt, PathExt};
+use crate::visibility::Visibilities;
 
 #[derive(Clone, PartialEq, Eq)]
 pub struct Forward {
@@ -96,7 +98,7 @@ impl Parse for Forward {
                                                         Ok((p, state))
                                                     } else {
                                                         Err(Error::unexpected_meta(&meta)
-                                                            .with_alts(&["Path"]))
+                                                            .with_alts(["Path"]))
                                                     }
                                                 }
                                                 // enable(forward("x"))
@@ -123,7 +125,7 @@ impl Parse for Forward {
                         // #[shorthand(enable(forward(x = "")))]
                         Meta::NameValue(_) => {
                             return Err(Error::unexpected_meta(&meta)
-                                .with_alts(&["Path", "List"])
+                                .with_alts(["Path", "List"])
                                 .into());
                         }
                     }
@@ -190,6 +192,161 @@ impl Forward {
     }
 }
 
+/// The attributes, that are only added to one of the derived functions, for
+/// example with `#[shorthand_get(must_use)]` to the getter.
+#[derive(Debug, Clone, Default)]
+pub struct AccessorAttributes {
+    get: Vec<syn::Attribute>,
+    set: Vec<syn::Attribute>,
+    try_set: Vec<syn::Attribute>,
+    get_mut: Vec<syn::Attribute>,
+    try_get_mut: Vec<syn::Attribute>,
+}
+
+impl AccessorAttributes {
+    /// The names of the helper attributes, the accessor is the name without
+    /// the `shorthand_` prefix.
+    pub const HELPERS: [&'static str; 5] = [
+        "shorthand_get",
+        "shorthand_set",
+        "shorthand_try_set",
+        "shorthand_get_mut",
+        "shorthand_try_get_mut",
+    ];
+
+    pub fn is_helper(path: &syn::Path) -> bool {
+        Self::HELPERS.iter().any(|helper| path.is_ident(helper))
+    }
+
+    /// Parses a helper attribute like `#[shorthand_get(must_use, cfg(test))]`,
+    /// where every item is an attribute of the accessor.
+    pub fn parse_helper(&mut self, meta: &Meta) -> Result<(), Error> {
+        let list = {
+            if let Meta::List(list) = meta {
+                list
+            } else {
+                return Err(Error::unexpected_meta(meta).with_alts(["List"]));
+            }
+        };
+
+        let mut errors = vec![];
+        let mut attributes = vec![];
+
+        for nested in &list.nested {
+            match nested {
+                NestedMeta::Meta(meta) => {
+                    attributes.push(syn::Attribute::from_token_stream(quote!(#[#meta]))?);
+                }
+                // #[shorthand_get("must_use")]
+                NestedMeta::Lit(value) => {
+                    errors.push(Error::unexpected_lit(value).with_alts(["Path"]));
+                }
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(Error::multiple(errors));
+        }
+
+        let accessor = meta.path().to_string();
+
+        self.extend(accessor.trim_start_matches("shorthand_"), attributes);
+
+        Ok(())
+    }
+
+    /// Parses `attrs(get = "#[must_use]", set = "#[track_caller]")`, where
+    /// each value can contain one or more outer attributes.
+    pub fn parse_attrs(&mut self, meta: &Meta) -> Result<(), Error> {
+        let list = {
+            if let Meta::List(list) = meta {
+                list
+            } else {
+                return Err(Error::unexpected_meta(meta).with_alts(["List"]));
+            }
+        };
+
+        let mut errors = vec![];
+
+        for nested in &list.nested {
+            let pair = {
+                match nested {
+                    NestedMeta::Meta(Meta::NameValue(pair)) => pair,
+                    NestedMeta::Meta(meta) => {
+                        errors.push(Error::unexpected_meta(meta).with_alts(["NameValue"]));
+                        continue;
+                    }
+                    NestedMeta::Lit(value) => {
+                        errors.push(Error::unexpected_lit(value).with_span(value));
+                        continue;
+                    }
+                }
+            };
+
+            let accessor = pair.path.to_string();
+
+            if !Visibilities::ACCESSORS.contains(&accessor.as_str()) {
+                errors.push(
+                    Error::unknown_field(&accessor)
+                        .with_alts(Visibilities::ACCESSORS)
+                        .with_span(&pair.path),
+                );
+                continue;
+            }
+
+            if let Lit::Str(value) = &pair.lit {
+                // the attributes will have the span of the string
+                match value.parse_with(Self::parse_outer) {
+                    Ok(attributes) => self.extend(&accessor, attributes),
+                    Err(err) => errors.push(Error::syn(err)),
+                }
+            } else {
+                errors.push(Error::unexpected_lit(&pair.lit).with_alts(["string"]));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::multiple(errors))
+        }
+    }
+
+    /// Parses at least one outer attribute like `#[must_use]`.
+    fn parse_outer(input: ParseStream) -> syn::Result<Vec<syn::Attribute>> {
+        if !input.peek(Token![#]) {
+            return Err(input.error("expected an attribute like `#[must_use]`"));
+        }
+
+        syn::Attribute::parse_outer(input)
+    }
+
+    /// Adds the `attributes` to the function `accessor` (for example `get`).
+    pub fn extend<I>(&mut self, accessor: &str, attributes: I)
+    where
+        I: IntoIterator<Item = syn::Attribute>,
+    {
+        match accessor {
+            "get" => self.get.extend(attributes),
+            "set" => self.set.extend(attributes),
+            "try_set" => self.try_set.extend(attributes),
+            "get_mut" => self.get_mut.extend(attributes),
+            "try_get_mut" => self.try_get_mut.extend(attributes),
+            _ => unreachable!("unknown accessor `{}`", accessor),
+        }
+    }
+
+    pub fn get(&self) -> &[syn::Attribute] { &self.get }
+
+    pub fn set(&self) -> &[syn::Attribute] { &self.set }
+
+    pub fn try_set(&self) -> &[syn::Attribute] { &self.try_set }
+
+    pub fn get_mut(&self) -> &[syn::Attribute] { &self.get_mut }
+
+    pub fn try_get_mut(&self) -> &[syn::Attribute] { &self.try_get_mut }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -204,6 +361,59 @@ mod tests {
         let _: Forward = syn::parse_str("#[shorthand(disable(forward(x, y, z)))]").unwrap();
     }
 
+    #[test]
+    fn parse_helper() {
+        let mut attributes = AccessorAttributes::default();
+
+        attributes
+            .parse_helper(
+                &syn::Attribute::from_str("#[shorthand_get(must_use, cfg(test))]")
+                    .unwrap()
+                    .parse_meta()
+                    .unwrap(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            attributes.get(),
+            &[
+                syn::Attribute::from_str("#[must_use]").unwrap(),
+                syn::Attribute::from_str("#[cfg(test)]").unwrap(),
+            ]
+        );
+        assert!(attributes.set().is_empty());
+
+        assert!(attributes
+            .parse_helper(
+                &syn::Attribute::from_str("#[shorthand_set = \"must_use\"]")
+                    .unwrap()
+                    .parse_meta()
+                    .unwrap(),
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn parse_attrs() {
+        let mut attributes = AccessorAttributes::default();
+
+        attributes
+            .parse_attrs(
+                &syn::parse_str(r##"attrs(set = "#[track_caller] #[doc(alias = \"put\")]")"##)
+                    .unwrap(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            attributes.set(),
+            &[
+                syn::Attribute::from_str("#[track_caller]").unwrap(),
+                syn::Attribute::from_str(r#"#[doc(alias = "put")]"#).unwrap(),
+            ]
+        );
+        assert!(attributes.get().is_empty());
+    }
+
     #[test]
     fn is_forward() {
         let valid_attributes = [
@@ -0,0 +1,181 @@
+//! This module generates the guard types, that are returned by mutable getters
+//! with the `guard` attribute.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{GenericParam, Generics, Ident, LifetimeDef, TypeParam};
+
+use crate::options::Options;
+
+/// Returns the generic arguments of `generics` without the angle brackets,
+/// for example `'a, T, N` for `<'a, T: Clone, const N: usize>`.
+pub fn generic_arguments(generics: &Generics) -> Vec<TokenStream> {
+    generics
+        .params
+        .iter()
+        .map(|param| {
+            match param {
+                GenericParam::Lifetime(def) => {
+                    let lifetime = &def.lifetime;
+                    quote!(#lifetime)
+                }
+                GenericParam::Type(ty) => {
+                    let ident = &ty.ident;
+                    quote!(#ident)
+                }
+                GenericParam::Const(constant) => {
+                    let ident = &constant.ident;
+                    quote!(#ident)
+                }
+            }
+        })
+        .collect()
+}
+
+/// Returns the generic arguments of a guard, for a field of the `types` (see
+/// [`extend_generics`]), that borrows the struct for `'_`.
+pub fn guard_arguments(generics: &Generics, types: &[TokenStream]) -> Vec<TokenStream> {
+    let mut result = generic_arguments(generics);
+    let position = generics.lifetimes().count();
+
+    for ty in types.iter().rev() {
+        result.insert(position, ty.clone());
+    }
+
+    result.insert(0, quote!('_));
+
+    result
+}
+
+pub fn guard_ident(name: &Ident) -> Ident { format_ident!("{}Guard", name) }
+
+pub fn try_guard_ident(name: &Ident) -> Ident { format_ident!("{}TryGuard", name) }
+
+/// Extends the generics of the struct with the lifetime `'__guard` and the
+/// `types` of the guard (for example the type `__T` of the guarded field).
+fn extend_generics(generics: &Generics, types: &[TokenStream]) -> Generics {
+    let mut result = generics.clone();
+
+    let lifetime: LifetimeDef = syn::parse2(quote!('__guard)).unwrap();
+    let position = result.lifetimes().count();
+
+    for ty in types.iter().rev() {
+        let ty: TypeParam = syn::parse2(ty.clone()).unwrap();
+        result.params.insert(position, GenericParam::Type(ty));
+    }
+
+    result.params.insert(0, GenericParam::Lifetime(lifetime));
+
+    result
+}
+
+/// Generates the `ExampleGuard` and the `ExampleTryGuard` struct for the
+/// struct `Example`.
+///
+/// The guards store the functions, that verify the struct, so the same guard
+/// can be used for fields with different `verify` attributes.
+pub fn generate(options: &Options) -> TokenStream {
+    let name = &options.ident;
+    let vis = &options.vis;
+    let (_, struct_generics, _) = options.generics.split_for_impl();
+    let self_ty = quote!(#name #struct_generics);
+
+    let guard = guard_ident(name);
+    let generics = extend_generics(&options.generics, &[quote!(__T)]);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let try_guard = try_guard_ident(name);
+    let fallible_generics = extend_generics(&options.generics, &[quote!(__T), quote!(__E)]);
+    let (try_impl_generics, try_ty_generics, try_where_clause) = fallible_generics.split_for_impl();
+
+    let guard_doc = format!(
+        "A mutable reference to a field of [`{name}`], that verifies the struct, when it is \
+         dropped."
+    );
+
+    let try_guard_doc = format!(
+        "A mutable reference to a field of [`{name}`], that has to be committed. If the \
+         verification fails or the guard is dropped without being committed, the previous \
+         value will be restored."
+    );
+
+    quote! {
+        #[doc = #guard_doc]
+        #[allow(dead_code)]
+        #vis struct #guard #impl_generics #where_clause {
+            inner: &'__guard mut #self_ty,
+            get: for<'__this> fn(&'__this #self_ty) -> &'__this __T,
+            get_mut: for<'__this> fn(&'__this mut #self_ty) -> &'__this mut __T,
+            verify: fn(&#self_ty),
+        }
+
+        impl #impl_generics ::core::ops::Deref for #guard #ty_generics #where_clause {
+            type Target = __T;
+
+            fn deref(&self) -> &Self::Target { (self.get)(&*self.inner) }
+        }
+
+        impl #impl_generics ::core::ops::DerefMut for #guard #ty_generics #where_clause {
+            fn deref_mut(&mut self) -> &mut Self::Target { (self.get_mut)(&mut *self.inner) }
+        }
+
+        impl #impl_generics ::core::ops::Drop for #guard #ty_generics #where_clause {
+            fn drop(&mut self) {
+                // verifying while unwinding could cause a double panic
+                if !::std::thread::panicking() {
+                    (self.verify)(&*self.inner);
+                }
+            }
+        }
+
+        #[doc = #try_guard_doc]
+        #[allow(dead_code)]
+        #vis struct #try_guard #try_impl_generics #try_where_clause {
+            inner: &'__guard mut #self_ty,
+            get: for<'__this> fn(&'__this #self_ty) -> &'__this __T,
+            get_mut: for<'__this> fn(&'__this mut #self_ty) -> &'__this mut __T,
+            verify: fn(&#self_ty) -> ::core::result::Result<(), __E>,
+            original: ::core::option::Option<__T>,
+        }
+
+        #[allow(dead_code)]
+        impl #try_impl_generics #try_guard #try_ty_generics #try_where_clause {
+            /// Verifies the modified value. If the verification fails, the previous
+            /// value will be restored and the error is returned.
+            pub fn commit(mut self) -> ::core::result::Result<(), __E> {
+                let result = (self.verify)(&*self.inner);
+
+                if result.is_ok() {
+                    self.original = None;
+                }
+
+                result
+            }
+        }
+
+        impl #try_impl_generics ::core::ops::Deref for #try_guard #try_ty_generics
+            #try_where_clause
+        {
+            type Target = __T;
+
+            fn deref(&self) -> &Self::Target { (self.get)(&*self.inner) }
+        }
+
+        impl #try_impl_generics ::core::ops::DerefMut for #try_guard #try_ty_generics
+            #try_where_clause
+        {
+            fn deref_mut(&mut self) -> &mut Self::Target { (self.get_mut)(&mut *self.inner) }
+        }
+
+        impl #try_impl_generics ::core::ops::Drop for #try_guard #try_ty_generics
+            #try_where_clause
+        {
+            fn drop(&mut self) {
+                // the guard has not been committed or the verification failed
+                if let Some(original) = self.original.take() {
+                    *(self.get_mut)(&mut *self.inner) = original;
+                }
+            }
+        }
+    }
+}
@@ -166,6 +166,23 @@
 //! }
 //! ```
 //!
+//! The visibility can also be changed for the individual functions, with the
+//! same names as the [`rename`](#rename) attribute (`get`, `set`, `try_set`,
+//! `get_mut` and `try_get_mut`). The functions, that are not mentioned keep
+//! their visibility.
+//!
+//! ```
+//! use shorthand::ShortHand;
+//!
+//! #[derive(ShortHand, Default)]
+//! #[shorthand(visibility(get = "pub", set = "pub(crate)"))]
+//! struct Example {
+//!     field: usize,
+//!     #[shorthand(visibility(get_mut = "pub(self)"), enable(get_mut))]
+//!     data: String,
+//! }
+//! ```
+//!
 //! ## `rename`
 //!
 //! This attribute allows you to rename the derived function, with a pattern.
@@ -248,6 +265,87 @@
 //! assert_eq!(Example::default().is_default(), false);
 //! ```
 //!
+//! A prefix or suffix, that is part of the field names, can be removed with
+//! `strip_prefix` and `strip_suffix`, before the names of the functions are
+//! derived. An empty string disables the stripping for a single field.
+//!
+//! ```
+//! use shorthand::ShortHand;
+//!
+//! #[derive(ShortHand, Default)]
+//! #[shorthand(strip_prefix = "m_")]
+//! struct Example {
+//!     m_count: usize,
+//!     #[shorthand(strip_prefix = "_", strip_suffix = "_")]
+//!     _name_: String,
+//!     #[shorthand(strip_prefix = "")]
+//!     m_data: usize,
+//! }
+//!
+//! let mut example = Example::default();
+//! example
+//!     .set_count(1)
+//!     .set_name("name".to_string())
+//!     .set_m_data(2);
+//!
+//! assert_eq!(example.count(), 1);
+//! assert_eq!(example.name(), &"name".to_string());
+//! assert_eq!(example.m_data(), 2);
+//! ```
+//!
+//! The case of the field name can be changed in the format string with
+//! `{:camel}`, `{:pascal}`, `{:upper}` and `{:snake}`. The names of all
+//! functions of a struct can be converted with `rename_all`, which accepts
+//! `"camelCase"`, `"PascalCase"`, `"SCREAMING_SNAKE_CASE"` and `"snake_case"`.
+//! This is useful for bindings to other languages, which have different naming
+//! conventions.
+//!
+//! ```
+//! use shorthand::ShortHand;
+//!
+//! #[derive(ShortHand, Default)]
+//! #[shorthand(rename_all = "camelCase")]
+//! struct Example {
+//!     field_name: usize,
+//!     #[shorthand(rename(get = "get{:pascal}"))]
+//!     other_field: usize,
+//! }
+//!
+//! let mut example = Example::default();
+//! example.setFieldName(1).setOtherField(2);
+//!
+//! assert_eq!(example.fieldName(), 1);
+//! assert_eq!(example.getOtherField(), 2);
+//! ```
+//!
+//! Getters of `bool` fields can have a different format, which is set with
+//! `rename(get_bool = "is_{}")` or `bool_prefix = "is_"`. Fields, that already
+//! start with `is_`, `has_` or the prefix, keep their name, so there is no
+//! `is_is_enabled`. If there is no format for `bool` fields, the format of the
+//! getter is used. `rename(get_bool = "...")` takes precedence over
+//! `bool_prefix`, which is not replaced by a `rename(...)` on the struct or a
+//! field, but can be disabled for a field with `bool_prefix = ""`.
+//!
+//! ```
+//! use shorthand::ShortHand;
+//!
+//! #[derive(ShortHand, Default)]
+//! #[shorthand(bool_prefix = "is_")]
+//! struct Example {
+//!     enabled: bool,
+//!     is_visible: bool,
+//!     has_value: bool,
+//!     value: usize,
+//! }
+//!
+//! let example = Example::default();
+//!
+//! assert_eq!(example.is_enabled(), false);
+//! assert_eq!(example.is_visible(), false);
+//! assert_eq!(example.has_value(), false);
+//! assert_eq!(example.value(), 0);
+//! ```
+//!
 //! ## `verify`
 //!
 //! This attribute allows you to verify wether or not a value passed to a setter
@@ -275,6 +373,525 @@
 //! example.set_field(true);
 //! ```
 //!
+//! A fallible function can be specified with `try_fn`, which has to return a
+//! `Result<(), error>`. It is called by the functions, that can return the
+//! error, which are the constructor, the `try_field_mut` functions of the
+//! [`guard`](derive.ShortHand.html#guard) attribute, `try_set_field` and the
+//! `set_field_from_str` functions of the [`parse`](derive.ShortHand.html#parse)
+//! attribute. All other functions, like the setters, call the infallible `fn`.
+//! `try_fn` can be used without `fn`, in which case these functions call
+//! `try_fn` and panic, if it returns an error.
+//!
+//! `try_set_field` sets the field and calls `try_fn`. If the verification
+//! fails, the previous value is restored and the error is returned. It can be
+//! renamed with `rename(try_verify = "...")`.
+//!
+//! ```
+//! use shorthand::ShortHand;
+//!
+//! #[derive(ShortHand)]
+//! #[shorthand(verify(try_fn = "Self::verify_field", error = "&'static str"))]
+//! struct Example {
+//!     field: bool,
+//! }
+//!
+//! impl Example {
+//!     fn verify_field(&self) -> Result<(), &'static str> {
+//!         if self.field {
+//!             Err("field must be `false`")
+//!         } else {
+//!             Ok(())
+//!         }
+//!     }
+//! }
+//!
+//! let mut example = Example { field: false };
+//!
+//! assert_eq!(
+//!     example.try_set_field(true).err(),
+//!     Some("field must be `false`")
+//! );
+//! assert_eq!(example.field(), false);
+//! ```
+//!
+//! ## Presets
+//!
+//! Presets are shorthands for common combinations of attributes:
+//!
+//! - `readonly` enables [`get`](derive.ShortHand.html#get) and disables
+//!   [`set`](derive.ShortHand.html#set),
+//!   [`try_into`](derive.ShortHand.html#try_into),
+//!   [`get_mut`](derive.ShortHand.html#get_mut) and
+//!   [`collection_magic`](derive.ShortHand.html#collection_magic).
+//! - `writeonly` enables [`set`](derive.ShortHand.html#set) and disables
+//!   [`get`](derive.ShortHand.html#get) and
+//!   [`get_mut`](derive.ShortHand.html#get_mut).
+//! - `opaque` disables all accessors, but unlike
+//!   [`skip`](derive.ShortHand.html#skip) the field is still known to the
+//!   derive.
+//!
+//! Presets are applied before `enable` and `disable`, so the attributes can be
+//! overridden, for example with `#[shorthand(opaque, enable(get))]`. Only
+//! enabling an attribute, that the preset already enabled (or disabling one,
+//! that it already disabled), is an error, because it is redundant.
+//!
+//! ```
+//! use shorthand::ShortHand;
+//!
+//! #[derive(ShortHand, Default)]
+//! #[shorthand(readonly)]
+//! struct Example {
+//!     id: usize,
+//!     #[shorthand(writeonly)]
+//!     secret: String,
+//!     #[shorthand(enable(get_mut))]
+//!     data: Vec<u8>,
+//! }
+//!
+//! let mut example = Example::default();
+//!
+//! assert_eq!(example.id(), 0);
+//! example.set_secret("hunter2".to_string());
+//! example.data_mut().push(1);
+//! ```
+//!
+//! ## Selecting fields
+//!
+//! Instead of adding [`skip`](derive.ShortHand.html#skip) to many fields, the
+//! fields can be selected on the struct:
+//!
+//! - `only(a, b)` derives functions only for the listed fields.
+//! - `except(c, d)` derives functions for all fields except the listed ones.
+//! - `exclude_prefix = "cache_"` ignores all fields, that start with the
+//!   prefix.
+//!
+//! Unknown fields in `only` and `except` are an error.
+//!
+//! ```
+//! use shorthand::ShortHand;
+//!
+//! #[derive(ShortHand, Default)]
+//! #[shorthand(except(secret), exclude_prefix = "cache_")]
+//! struct Example {
+//!     value: usize,
+//!     secret: String,
+//!     cache_value: Option<usize>,
+//! }
+//!
+//! let mut example = Example::default();
+//! example.set_value(1);
+//!
+//! assert_eq!(example.value(), 1);
+//! ```
+//!
+//! ## Constructor
+//!
+//! `#[shorthand(constructor)]` on the struct derives a `new` function with one
+//! argument for every field. The arguments are converted like in the setter,
+//! so `into` and `strip_option` are applied to them as well.
+//!
+//! Fields with `#[shorthand(default)]` are initialized with
+//! [`Default::default`] and fields with `#[shorthand(default = "expr")]` with
+//! the expression, instead of being an argument. Skipped fields are
+//! initialized with [`Default::default`] as well, so their type has to
+//! implement [`Default`], unless they have a `default = "expr"`. Fields, that
+//! are only excluded from the accessors with `only`, `except`,
+//! `exclude_prefix` or `ignore_underscore`, are still arguments.
+//!
+//! ```
+//! use shorthand::ShortHand;
+//!
+//! #[derive(ShortHand)]
+//! #[shorthand(constructor)]
+//! struct Example {
+//!     #[shorthand(enable(into))]
+//!     name: String,
+//!     #[shorthand(enable(strip_option))]
+//!     value: Option<usize>,
+//!     #[shorthand(default = "vec![1, 2]")]
+//!     data: Vec<u8>,
+//! }
+//!
+//! let example = Example::new("example", 1);
+//!
+//! assert_eq!(example.name(), &"example".to_string());
+//! assert_eq!(example.value(), Some(1));
+//! assert_eq!(example.data(), &vec![1, 2]);
+//! ```
+//!
+//! The constructor is a `const fn`, if `const_fn` is enabled on the struct.
+//! `Default::default()` and the `verify` functions can not be called in a
+//! `const fn`, so every field, that is not an argument (except for
+//! `PhantomData`), needs a constant `default = "expr"` and the struct can not
+//! have a `verify` function.
+//! The `verify` functions are called once with the new struct. If there is a
+//! `try_fn`, the constructor returns a `Result<Self, error>`.
+//!
+//! ## Default values
+//!
+//! Every field with a default value gets a `reset_<field>()` function, that
+//! sets the field to its default value, and `reset()` resets all of these
+//! fields at once. The name can be changed with `rename(reset = "...")`.
+//!
+//! `#[shorthand(default_impl)]` on the struct implements [`Default`], where the
+//! fields without a default value are initialized with
+//! [`Default::default`]:
+//!
+//! ```
+//! use shorthand::ShortHand;
+//!
+//! #[derive(ShortHand)]
+//! #[shorthand(default_impl)]
+//! struct Example {
+//!     #[shorthand(default = "8080")]
+//!     port: u16,
+//!     #[shorthand(default)]
+//!     headers: Vec<String>,
+//!     name: String,
+//! }
+//!
+//! let mut example = Example::default();
+//! assert_eq!(example.port(), 8080);
+//!
+//! example.set_port(1).set_name("name".to_string());
+//! example.reset();
+//!
+//! assert_eq!(example.port(), 8080);
+//! assert_eq!(example.name(), &"name".to_string());
+//! ```
+//!
+//! ## Parts
+//!
+//! `#[shorthand(parts)]` derives a struct `ExampleParts` (for a struct named
+//! `Example`), that has a public field for every field of the struct, even for
+//! the skipped ones. The struct can be split into its parts with
+//! `into_parts` and be created from them with `from_parts`. The parts can be
+//! changed in between, so `from_parts` calls the `verify` functions like the
+//! constructor and returns a `Result<Self, error>`, if there is a `try_fn`:
+//!
+//! ```
+//! use shorthand::ShortHand;
+//!
+//! #[derive(ShortHand)]
+//! #[shorthand(parts)]
+//! struct Example {
+//!     name: String,
+//!     #[shorthand(enable(skip))]
+//!     data: Vec<u8>,
+//! }
+//!
+//! let example = Example {
+//!     name: "example".to_string(),
+//!     data: vec![1, 2],
+//! };
+//!
+//! let ExampleParts { name, data } = example.into_parts();
+//! assert_eq!(name, "example".to_string());
+//!
+//! let example = Example::from_parts(ExampleParts { name, data });
+//! assert_eq!(example.name(), &"example".to_string());
+//! ```
+//!
+//! ## Views
+//!
+//! Every getter borrows the entire struct, so it is not possible to hold
+//! mutable references to two fields at the same time. `#[shorthand(views)]`
+//! derives the structs `ExampleRef` and `ExampleMut` (for a struct named
+//! `Example`), that have a reference to every field, which are returned by
+//! `fields_ref` and `fields_mut`:
+//!
+//! ```
+//! use shorthand::ShortHand;
+//!
+//! #[derive(ShortHand, Default)]
+//! #[shorthand(views, enable(get_mut))]
+//! struct Example {
+//!     name: String,
+//!     values: Vec<usize>,
+//!     #[shorthand(disable(get_mut))]
+//!     count: usize,
+//! }
+//!
+//! let mut example = Example::default();
+//! let ExampleMut {
+//!     name,
+//!     values,
+//!     count,
+//!     ..
+//! } = example.fields_mut();
+//!
+//! name.push_str("example");
+//! values.push(*count);
+//!
+//! assert_eq!(example.fields_ref().name, &"example".to_string());
+//! ```
+//!
+//! `ExampleRef` has a field for every field with a getter. `ExampleMut` has a
+//! mutable reference to every field with a mutable getter and an immutable
+//! reference to the other fields with a getter or a setter. A field with a
+//! `guard` or a `verify` function is borrowed immutably as well, because the
+//! view would bypass them, so they can only be changed through the functions
+//! of the struct. Skipped fields are
+//! not part of the views and the fields of the views have the same visibility
+//! as the corresponding function. The documentation and `#[cfg(...)]` of the
+//! fields are forwarded to the views.
+//!
+//! ## Patches
+//!
+//! `#[shorthand(patch)]` derives a struct `ExamplePatch` (for a struct named
+//! `Example`), that has an `Option` for every field with a setter. The fields
+//! of a patch, that are `Some`, are set with `apply_patch`, which calls the
+//! setters, so the `verify` function is called as well.
+//!
+//! `#[shorthand(patch(diff))]` derives `diff` as well, which returns the
+//! patch, that changes a value into another one. The fields of the patch have
+//! to implement [`PartialEq`] and [`Clone`], which is not required without
+//! `diff`:
+//!
+//! ```
+//! use shorthand::ShortHand;
+//!
+//! #[derive(ShortHand, Clone, Default)]
+//! #[shorthand(patch(diff))]
+//! struct Example {
+//!     name: String,
+//!     port: u16,
+//! }
+//!
+//! let mut example = Example::default();
+//! example.apply_patch(ExamplePatch {
+//!     port: Some(8080),
+//!     ..ExamplePatch::default()
+//! });
+//!
+//! assert_eq!(example.port(), 8080);
+//!
+//! let mut other = example.clone();
+//! other.set_name("other".to_string());
+//!
+//! let patch = example.diff(&other);
+//! assert_eq!(patch.name, Some("other".to_string()));
+//! assert_eq!(patch.port, None);
+//! ```
+//!
+//! A field of an `Option` with
+//! [`strip_option`](derive.ShortHand.html#strip_option) is set to `None`
+//! directly, because the setter only accepts the value.
+//!
+//! ## Events
+//!
+//! `#[shorthand(events)]` derives an enum `ExampleEvent` (for a struct named
+//! `Example`), that has a variant with the new value for every field with a
+//! setter. `apply` sets the field and returns the inverse event, which has the
+//! previous value, so the events can be used to undo and redo changes.
+//!
+//! The variants are named after the fields in `PascalCase`, which can be
+//! changed with `rename(event = "Set{:pascal}")`. Traits can be derived for the
+//! enum with `events(derive(Debug, Clone))` and with
+//! `events(record = "Self::record")` every setter calls the function with the
+//! inverse event, after the field has been changed and verified. The events are
+//! recorded by the setter, `try_<field>`, `reset_<field>` and `reset()` and by
+//! the functions, that call the setters (`apply_patch`, `set_dyn` and
+//! `set_<field>_from_str`), even if they set an `Option` with `strip_option` to
+//! `None`. `push_<field>`, `insert_<field>` and the mutable getters (with or
+//! without a `guard`) do not record events, because they would have to clone
+//! the entire field for the inverse event:
+//!
+//! ```
+//! use shorthand::ShortHand;
+//!
+//! #[derive(ShortHand, Default)]
+//! #[shorthand(events(record = "Self::record", derive(Debug, PartialEq)))]
+//! struct Example {
+//!     name: String,
+//!     #[shorthand(enable(skip))]
+//!     history: Vec<ExampleEvent>,
+//! }
+//!
+//! impl Example {
+//!     fn record(&mut self, inverse: ExampleEvent) { self.history.push(inverse); }
+//! }
+//!
+//! let mut example = Example::default();
+//! example.set_name("example".to_string());
+//!
+//! assert_eq!(example.history, vec![ExampleEvent::Name(String::new())]);
+//!
+//! // undo the last change
+//! let event = example.history.pop().unwrap();
+//! let redo = event.apply(&mut example);
+//!
+//! assert_eq!(example.name(), &String::new());
+//! assert_eq!(redo, ExampleEvent::Name("example".to_string()));
+//! ```
+//!
+//! ## Field information
+//!
+//! `#[shorthand(field_info)]` adds the associated constant `FIELDS`, which has
+//! a [`FieldInfo`] for every field, in the order of their declaration. It has
+//! the name and the type of the field, the names of the derived functions and
+//! whether the field is read-only, skipped or returned as a copy.
+//!
+//! `FieldInfo` is part of the `shorthand_runtime` crate, because a proc-macro
+//! crate can not export types, so it has to be added to the dependencies as
+//! well:
+//!
+//! ```
+//! use shorthand::ShortHand;
+//!
+//! #[derive(ShortHand)]
+//! #[shorthand(field_info)]
+//! struct Example {
+//!     name: String,
+//!     #[shorthand(disable(set))]
+//!     port: u16,
+//!     #[shorthand(enable(skip))]
+//!     cache: Vec<u8>,
+//! }
+//!
+//! let port = &Example::FIELDS[1];
+//!
+//! assert_eq!(port.name, "port");
+//! assert_eq!(port.type_name, "u16");
+//! assert_eq!(port.accessors, &["port"]);
+//! assert!(port.read_only && port.copy && !port.skipped);
+//!
+//! assert!(Example::FIELDS[0].has_accessor("set_name"));
+//! assert!(Example::FIELDS[2].skipped);
+//! ```
+//!
+//! [`FieldInfo`]: https://docs.rs/shorthand_runtime
+//!
+//! ## Dynamic access
+//!
+//! `#[shorthand(dynamic)]` adds `get_dyn(&self, name: &str)`, which returns
+//! the field with the `name` as `Option<&dyn Any>`, if it has a getter, and
+//! `set_dyn(&mut self, name: &str, value: Box<dyn Any>)`, which calls the
+//! setter of the field, so the struct is still verified. `set_dyn` returns a
+//! `SetError` from the `shorthand_runtime` crate, if there is no field with the
+//! `name`, the field has no setter or the value has the wrong type. If the
+//! field has a fallible verify function (`try_fn`), `set_dyn` calls
+//! `try_set_field` instead of the setter and returns the error of the verify
+//! function in `SetError::Invalid`, so the error type has to be `'static`.
+//!
+//! Both functions are only available, if the types of the fields are
+//! `'static`.
+//!
+//! ```
+//! use shorthand::ShortHand;
+//! use shorthand_runtime::SetError;
+//!
+//! #[derive(ShortHand, Default)]
+//! #[shorthand(dynamic)]
+//! struct Example {
+//!     name: String,
+//!     #[shorthand(disable(set))]
+//!     port: u16,
+//! }
+//!
+//! let mut example = Example::default();
+//! example.set_dyn("name", Box::new("example".to_string()))?;
+//!
+//! assert_eq!(example.name(), "example");
+//! assert_eq!(
+//!     example.get_dyn("port").unwrap().downcast_ref::<u16>(),
+//!     Some(&0)
+//! );
+//! assert!(matches!(
+//!     example.set_dyn("port", Box::new(1_u16)),
+//!     Err(SetError::ReadOnly)
+//! ));
+//! # Ok::<(), SetError>(())
+//! ```
+//!
+//! ## Name collisions
+//!
+//! Two fields can derive a function with the same name, for example the
+//! setter of `value` and the getter of `set_value`. This is reported as an
+//! error, that points at both fields. The same applies to the functions and
+//! constants, that are derived for the entire struct (`new`, `reset`,
+//! `into_parts`, `from_parts`, `fields_ref`, `fields_mut`, `apply_patch`,
+//! `diff`, `get_dyn`, `set_dyn`, `set_by_name_from_str` and `FIELDS`), so a
+//! field called `new` can not have a getter, if the struct has a `constructor`.
+//!
+//! The derive does not know the functions, that you implemented yourself. Their
+//! names can be reserved with `reserved(...)` on the struct, so a derived
+//! function with the same name is an error as well:
+//!
+//! ```compile_fail
+//! use shorthand::ShortHand;
+//!
+//! #[derive(ShortHand)]
+//! #[shorthand(reserved(len))]
+//! struct Example {
+//!     len: usize, // error: the function `len` of the field `len` has a reserved name
+//!     data: Vec<u8>,
+//! }
+//!
+//! impl Example {
+//!     pub fn len(&self) -> usize { self.data.len() }
+//! }
+//! ```
+//!
+//! ## `profile`
+//!
+//! Attributes, that are repeated on many structs, can be defined once as a
+//! profile in a `shorthand.toml` next to the `Cargo.toml` of your crate:
+//!
+//! ```toml
+//! [profile.domain]
+//! enable = ["must_use", "into"]
+//! disable = ["primitive_copy"]
+//! visibility = "pub(crate)"
+//! ```
+//!
+//! A profile replaces the defaults of a struct and can only be applied to
+//! structs. The attributes of the struct are parsed after the profile, so
+//! they can adjust it.
+//!
+//! ```ignore
+//! use shorthand::ShortHand;
+//!
+//! #[derive(ShortHand, Default)]
+//! #[shorthand(profile = "domain")]
+//! struct Example {
+//!     value: usize,
+//! }
+//!
+//! let mut example = Example::default();
+//! example.set_value(1_usize);
+//!
+//! assert_eq!(example.value(), &1);
+//! ```
+//!
+//! ## Configuration
+//!
+//! The defaults of all structs in a crate can be changed in the `[defaults]`
+//! table of the `shorthand.toml`. The table accepts `enable`, `disable`,
+//! `visibility`, `rename`, `inline` and `bool_prefix`:
+//!
+//! ```toml
+//! [defaults]
+//! disable = ["must_use"]
+//! inline = "hint"
+//! visibility = "pub(crate)"
+//! ```
+//!
+//! If there is no `shorthand.toml`, the `[package.metadata.shorthand]` table
+//! of the `Cargo.toml` is used instead:
+//!
+//! ```toml
+//! [package.metadata.shorthand.defaults]
+//! disable = ["inline"]
+//!
+//! [package.metadata.shorthand.profile.domain]
+//! enable = ["must_use"]
+//! ```
+//!
+//! The defaults are applied before the [`profile`](#profile) and the
+//! attributes of the struct. Repeating one of the defaults in an attribute is
+//! not redundant, so a struct does not break, if the defaults change.
+//!
 //! ## List of Attributes
 //! - [`option_as_ref`](derive.ShortHand.html#option_as_ref)
 //! - [`const_fn`](derive.ShortHand.html#const_fn)
@@ -295,6 +912,10 @@
 //! - [`collection_magic`](derive.ShortHand.html#collection_magic)
 //! - [`strip_option`](derive.ShortHand.html#strip_option)
 //! - [`clone`](derive.ShortHand.html#clone)
+//! - [`guard`](derive.ShortHand.html#guard)
+//! - [`into_field`](derive.ShortHand.html#into_field)
+//! - [`parse`](derive.ShortHand.html#parse)
+//! - [`doc`](derive.ShortHand.html#doc)
 //!
 //! ### Enabled by default
 //!
@@ -306,6 +927,7 @@
 //! - [`set`](#set)
 //! - [`ignore_phantomdata`](#ignore_phantomdata)
 //! - [`forward_attributes`](#forward_attributes)
+//! - [`doc`](#doc)
 //!
 //! [`enable`]: #enable
 //!
@@ -341,14 +963,26 @@
 extern crate proc_macro;
 
 mod attributes;
+mod collision;
+mod config;
+mod constructor;
+mod doc;
+mod dynamic;
 mod error;
+mod events;
 mod expand;
+mod field_info;
 mod forward;
+mod guard;
 mod options;
 mod parser;
+mod parts;
+mod patch;
 mod rename;
+mod selection;
 mod utils;
 mod verify;
+mod views;
 mod visibility;
 
 pub(crate) use error::Error;
@@ -485,6 +1119,27 @@ use syn::{parse_macro_input, DeriveInput};
 ///
 /// This attribute is enabled by default.
 ///
+/// The level of the attribute can be changed for a struct or a field with
+/// `inline(hint)` (`#[inline]`), `inline(always)` (`#[inline(always)]`) or
+/// `inline(never)` (`#[inline(never)]`):
+///
+/// ```
+/// use shorthand::ShortHand;
+///
+/// #[derive(ShortHand, Default)]
+/// #[shorthand(inline(hint))]
+/// struct Example {
+///     field: usize,
+///     #[shorthand(inline(never))]
+///     other: usize,
+/// }
+///
+/// let example = Example::default();
+///
+/// assert_eq!(example.field(), 0);
+/// assert_eq!(example.other(), 0);
+/// ```
+///
 /// ## `must_use`
 ///
 /// This attribute will mark functions with `#[must_use]`,
@@ -622,13 +1277,27 @@ use syn::{parse_macro_input, DeriveInput};
 /// assert_eq!(example.other(), &"other".to_string());
 /// ```
 ///
-/// This struct uses `VALUE` as a generic, so you should *NOT* use that on your
-/// struct
+/// The generic of the setter is called `VALUE`. If your struct already has a
+/// generic with that name, underscores are appended to the generic of the
+/// setter (`VALUE_`). The name can also be chosen with `generic = "..."`,
+/// which applies to [`try_into`](#try_into) as well:
 ///
 /// ```
-/// struct DoNotDoThis<VALUE> {
+/// use shorthand::ShortHand;
+///
+/// #[derive(ShortHand, Default)]
+/// #[shorthand(enable(into), generic = "V")]
+/// struct Example<VALUE> {
+///     field: String,
 ///     value: VALUE,
 /// }
+///
+/// let mut example = Example::<usize>::default();
+///
+/// example.set_field::<&str>("field");
+/// example.set_value(1_usize);
+///
+/// assert_eq!(example.field(), &"field".to_string());
 /// ```
 ///
 /// This attribute is not enabled by default.
@@ -734,6 +1403,8 @@ use syn::{parse_macro_input, DeriveInput};
 /// # }
 /// #
 /// impl Example {
+///     /// Returns a reference to `data`.
+///     ///
 ///     /// Data has some special restrictions.
 ///     /// - The String can only exist of uppercase characters
 ///     /// - Numbers are allowed
@@ -747,6 +1418,8 @@ use syn::{parse_macro_input, DeriveInput};
 ///     #[inline(always)]
 ///     pub fn data(&self) -> &String { &self.data }
 ///
+///     /// Sets `data` and returns `&mut Self`.
+///     ///
 ///     /// Data has some special restrictions.
 ///     /// - The String can only exist of uppercase characters
 ///     /// - Numbers are allowed
@@ -768,11 +1441,76 @@ use syn::{parse_macro_input, DeriveInput};
 /// as you can see the line `/// This part will not be forwarded.` did not get
 /// forwarded.
 ///
+/// Attributes, that should only be added to one of the derived functions, can
+/// be specified with `#[shorthand_get(...)]`, `#[shorthand_set(...)]`,
+/// `#[shorthand_try_set(...)]`, `#[shorthand_get_mut(...)]` and
+/// `#[shorthand_try_get_mut(...)]`. Every item is added as an attribute to the
+/// function:
+///
+/// ```
+/// use shorthand::ShortHand;
+///
+/// #[derive(ShortHand, Default)]
+/// struct Example {
+///     #[shorthand_get(must_use)]
+///     #[shorthand_set(deprecated(note = "the value should not be changed"))]
+///     value: usize,
+/// }
+///
+/// let example = Example::default();
+///
+/// assert_eq!(example.value(), 0);
+/// ```
+///
+/// The same can be done with `#[shorthand(attrs(...))]`, which takes the
+/// attributes as a string. This also works on the struct, in which case the
+/// attributes are added to the functions of every field:
+///
+/// ```
+/// use shorthand::ShortHand;
+///
+/// #[derive(ShortHand, Default)]
+/// #[shorthand(attrs(set = "#[track_caller]"))]
+/// struct Example {
+///     #[shorthand(attrs(get = "#[must_use] #[doc(alias = \"size\")]"))]
+///     value: usize,
+/// }
+///
+/// let mut example = Example::default();
+/// example.set_value(1);
+///
+/// assert_eq!(example.value(), 1);
+/// ```
+///
 /// ## `ignore_underscore`
 ///
 /// This attribute instructs the [`proc_macro`] to ignore fields prefixed
 /// with an `_`.
 ///
+/// ```
+/// use shorthand::ShortHand;
+///
+/// #[derive(ShortHand, Default)]
+/// #[shorthand(enable(ignore_underscore))]
+/// struct Example {
+///     _private: usize,
+///     public: usize,
+/// }
+///
+/// impl Example {
+///     // this would conflict with the getter, if it was derived
+///     pub fn _private(&self) -> &'static str { "private" }
+/// }
+///
+/// let example = Example::default();
+///
+/// assert_eq!(example._private(), "private");
+/// assert_eq!(example.public(), 0);
+/// ```
+///
+/// If the functions should be derived without the `_`, you can use
+/// [`strip_prefix`](index.html#rename) instead.
+///
 /// This attribute is not enabled by default.
 ///
 /// ## `collection_magic`
@@ -823,6 +1561,31 @@ use syn::{parse_macro_input, DeriveInput};
 /// });
 /// ```
 ///
+/// The functions can be renamed with `rename(push = "...")` and
+/// `rename(insert = "...")`. A `rename(format = "...")` is applied to them as
+/// well:
+///
+/// ```
+/// use shorthand::ShortHand;
+///
+/// #[derive(ShortHand, Default)]
+/// #[shorthand(enable(collection_magic))]
+/// struct Example {
+///     #[shorthand(rename(push = "add_{}"))]
+///     value: Vec<usize>,
+///     #[shorthand(rename(format = "my_{}"))]
+///     other: Vec<usize>,
+/// }
+///
+/// let mut example = Example::default();
+///
+/// example.add_value(1);
+/// example.push_my_other(2);
+///
+/// assert_eq!(example.value(), &vec![1]);
+/// assert_eq!(example.my_other(), &vec![2]);
+/// ```
+///
 /// ## `strip_option`
 ///
 /// This will change the input type for setter of optional fields from
@@ -863,7 +1626,183 @@ use syn::{parse_macro_input, DeriveInput};
 /// ```
 ///
 /// This attribute is diabled by default.
-#[proc_macro_derive(ShortHand, attributes(shorthand))]
+///
+/// ## `guard`
+///
+/// A mutable getter ([`get_mut`](#get_mut)) allows to change a field without
+/// calling the [`verify`](index.html#verify) function. With this attribute the
+/// mutable getter returns a guard instead of a `&mut T`, which dereferences to
+/// the field and calls the `verify` function, when it is dropped.
+///
+/// ```should_panic
+/// use shorthand::ShortHand;
+///
+/// #[derive(ShortHand, Default)]
+/// #[shorthand(verify(fn = "Self::verify"), enable(get_mut, guard))]
+/// struct Example {
+///     value: usize,
+/// }
+///
+/// impl Example {
+///     fn verify(&self) {
+///         if self.value > 10 {
+///             panic!("value must not be greater than 10");
+///         }
+///     }
+/// }
+///
+/// let mut example = Example::default();
+///
+/// *example.value_mut() = 11; // the guard is dropped here and panics
+/// ```
+///
+/// If there is a fallible verify function (`try_fn`), an additional
+/// `try_value_mut` function will be derived, which returns a guard that has to
+/// be committed. If the verification fails or the guard is dropped without
+/// being committed, the previous value will be restored.
+///
+/// ```
+/// use shorthand::ShortHand;
+///
+/// #[derive(ShortHand, Default)]
+/// #[shorthand(
+///     verify(fn = "Self::assert", try_fn = "Self::verify", error = "String"),
+///     enable(get_mut, guard)
+/// )]
+/// struct Example {
+///     value: usize,
+/// }
+///
+/// impl Example {
+///     fn assert(&self) { self.verify().unwrap(); }
+///
+///     fn verify(&self) -> Result<(), String> {
+///         if self.value > 10 {
+///             Err("value must not be greater than 10".to_string())
+///         } else {
+///             Ok(())
+///         }
+///     }
+/// }
+///
+/// let mut example = Example::default();
+///
+/// let mut value = example.try_value_mut();
+/// *value = 11;
+/// assert!(value.commit().is_err());
+///
+/// assert_eq!(example.value(), 0);
+/// ```
+///
+/// The guards are called `ExampleGuard` and `ExampleTryGuard` (for a struct
+/// named `Example`) and have the same visibility as the struct. The field of a
+/// `try_value_mut` function has to implement [`Clone`].
+///
+/// This attribute is disabled by default.
+///
+/// ## `into_field`
+///
+/// Derives a function, that consumes the struct and returns the field. The
+/// function is called `into_field` for a field named `field` and can be
+/// renamed with `rename(into_field = "...")`.
+///
+/// ```
+/// use shorthand::ShortHand;
+///
+/// #[derive(ShortHand, Default)]
+/// #[shorthand(enable(into_field))]
+/// struct Example {
+///     name: String,
+///     data: Vec<u8>,
+/// }
+///
+/// let example = Example::default();
+///
+/// assert_eq!(example.into_data(), vec![]);
+/// ```
+///
+/// This attribute is disabled by default.
+///
+/// ## `parse`
+///
+/// Derives a function, that parses a string with [`FromStr`] and sets the
+/// field with its setter, which is called `set_field_from_str` for a field
+/// named `field` and can be renamed with `rename(parse = "...")`. The string is
+/// parsed into the `T` of an `Option<T>`. The struct also gets
+/// `set_by_name_from_str(name, s)`, which calls the function of the field with
+/// the `name`. A field named `by_name` would derive the same function, which
+/// is reported as a [name collision](index.html#name-collisions).
+///
+/// The functions return a `FieldParseError` from the `shorthand_runtime`
+/// crate, which has the name of the field.
+///
+/// ```
+/// use shorthand::ShortHand;
+/// use shorthand_runtime::FieldParseError;
+///
+/// #[derive(ShortHand, Default)]
+/// #[shorthand(enable(parse))]
+/// struct Example {
+///     port: u16,
+///     timeout: Option<u64>,
+/// }
+///
+/// let mut example = Example::default();
+/// example.set_port_from_str("8080")?;
+/// example.set_by_name_from_str("timeout", "30")?;
+///
+/// assert_eq!(example.port(), 8080);
+/// assert_eq!(example.timeout(), Some(30));
+/// assert_eq!(
+///     example.set_port_from_str("port").err().unwrap().field(),
+///     "port"
+/// );
+/// # Ok::<(), FieldParseError>(())
+/// ```
+///
+/// This attribute is disabled by default and only works for fields with a
+/// setter.
+///
+/// [`FromStr`]: std::str::FromStr
+///
+/// ## `doc`
+///
+/// The derived functions are documented, for example a getter with "Returns a
+/// reference to `field`." and a setter with "Sets `field` and returns `&mut
+/// Self`.". The (forwarded) documentation of the field is added after the
+/// generated documentation. Getters with the [`must_use`](#must_use)
+/// attribute get an additional note, why the returned value should be used.
+///
+/// The documentation can be changed with a template for each function (`get`,
+/// `set`, `try_set`, `get_mut` and `try_get_mut`), where `{field}` is replaced
+/// with the name and `{type}` with the type of the field:
+///
+/// ```
+/// use shorthand::ShortHand;
+///
+/// #[derive(ShortHand, Default)]
+/// #[shorthand(doc(get = "Returns the `{type}` of `{field}`."))]
+/// struct Example {
+///     /// The name of the example.
+///     #[shorthand(doc(set = "Renames the example."))]
+///     name: String,
+///     #[shorthand(disable(doc))]
+///     value: usize,
+/// }
+/// ```
+///
+/// This attribute is enabled by default.
+#[proc_macro_derive(
+    ShortHand,
+    attributes(
+        shorthand,
+        shorthand_get,
+        shorthand_set,
+        shorthand_try_set,
+        shorthand_get_mut,
+        shorthand_try_get_mut
+    )
+)]
 pub fn shorthand(input: TokenStream) -> TokenStream {
     // Parse the input tokens into a syntax tree
     let input = parse_macro_input!(input as DeriveInput);
@@ -1,32 +1,137 @@
+use std::path::PathBuf;
+
 use quote::quote;
-use syn::{Data, DeriveInput, Generics, Ident, Meta, NestedMeta, Visibility};
+use syn::{Data, DeriveInput, Generics, Ident, Lit, Meta, NestedMeta, Visibility};
 
-use crate::attributes::Attributes;
+use crate::attributes::{Attributes, AttributesBuilder};
+use crate::collision::Reserved;
+use crate::config::Config;
+use crate::constructor::DefaultValue;
+use crate::doc::Docs;
 use crate::error::Error;
-use crate::forward::Forward;
-use crate::rename::Rename;
+use crate::events::Events;
+use crate::forward::{AccessorAttributes, Forward};
+use crate::rename::{Case, Format, Rename, Strip};
+use crate::selection::Selection;
 use crate::utils::{MetaExt, PathExt};
 use crate::verify::Verify;
-use crate::visibility::FieldVisibility;
+use crate::visibility::{FieldVisibility, Visibilities};
 
 #[derive(Debug, Clone)]
-pub(crate) struct Options {
+pub struct Options {
     pub ident: Ident,
     pub attrs: Vec<syn::Attribute>,
     pub vis: Visibility,
     pub generics: Generics,
     pub data: Data,
     forward: Forward,
+    pub accessor_attrs: AccessorAttributes,
 
-    pub visibility: Visibility,
+    pub visibility: Visibilities,
     pub attributes: Attributes,
     pub rename: Rename,
+    pub strip: Strip,
+    pub rename_all: Option<Case>,
+    /// The format of getters for `bool` fields from `bool_prefix`, which is
+    /// not replaced by `rename(...)`.
+    pub bool_prefix: Option<Format>,
     pub verify: Verify,
+    pub docs: Docs,
+    pub selection: Selection,
+    pub reserved: Reserved,
+    /// The name of the generic of setters with `into` or `try_into`.
+    pub generic: Option<Ident>,
+    /// The functions and items, that are derived for the entire struct.
+    derives: Vec<Derive>,
+    pub events: Events,
+    /// The value of the field, if it is not an argument of the constructor.
+    pub default: Option<DefaultValue>,
+    /// The path of the `shorthand.toml`, that has been read.
+    pub config_path: Option<PathBuf>,
+    /// The attributes, that are changed by the defaults of the configuration.
+    configured: Vec<&'static str>,
     is_initial: bool,
 }
 
+/// The functions and items, that can be derived for the entire struct, for
+/// example with `#[shorthand(constructor)]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Derive {
+    /// The constructor `new`.
+    Constructor,
+    /// The implementation of `Default`, with the default values of the fields.
+    Default,
+    /// The `ExampleParts` struct with `into_parts` and `from_parts`.
+    Parts,
+    /// The `ExampleRef` and `ExampleMut` structs with `fields_ref` and
+    /// `fields_mut`.
+    Views,
+    /// The `ExamplePatch` struct with `apply_patch`.
+    Patch,
+    /// The `diff` function of the patch, which is enabled with
+    /// `#[shorthand(patch(diff))]`.
+    Diff,
+    /// The `ExampleEvent` enum.
+    Events,
+    /// The associated constant `FIELDS`.
+    FieldInfo,
+    /// The functions `get_dyn` and `set_dyn`.
+    Dynamic,
+}
+
+impl Derive {
+    const NAMES: [(&'static str, Self); 8] = [
+        ("constructor", Self::Constructor),
+        ("default_impl", Self::Default),
+        ("parts", Self::Parts),
+        ("views", Self::Views),
+        ("patch", Self::Patch),
+        ("events", Self::Events),
+        ("field_info", Self::FieldInfo),
+        ("dynamic", Self::Dynamic),
+    ];
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::NAMES
+            .iter()
+            .find(|(other, _)| *other == name)
+            .map(|(_, derive)| *derive)
+    }
+}
+
 impl Options {
-    const FIELDS: [&'static str; 5] = ["enable", "disable", "visibility", "rename", "verify"];
+    const FIELDS: [&'static str; 30] = [
+        "enable",
+        "disable",
+        "visibility",
+        "rename",
+        "verify",
+        "readonly",
+        "writeonly",
+        "opaque",
+        "profile",
+        "inline",
+        "doc",
+        "attrs",
+        "only",
+        "except",
+        "exclude_prefix",
+        "strip_prefix",
+        "strip_suffix",
+        "rename_all",
+        "bool_prefix",
+        "reserved",
+        "generic",
+        "constructor",
+        "default_impl",
+        "default",
+        "parts",
+        "views",
+        "patch",
+        "events",
+        "field_info",
+        "dynamic",
+    ];
 
     fn parse_attributes<T>(mut result: Self, attrs: &T) -> Result<Self, Error>
     where
@@ -63,9 +168,28 @@ impl Options {
                 continue;
             }
 
-            if let "shorthand" = attr.path.to_string().as_str() {
+            // #[shorthand_get(must_use)]
+            if AccessorAttributes::is_helper(&attr.path) {
+                if let Err(err) = result.accessor_attrs.parse_helper(&meta) {
+                    errors.push(err);
+                }
+
+                continue;
+            }
+
+            if attr.path.to_string().as_str() == "shorthand" {
                 if let Meta::List(data) = meta {
-                    for item in &data.nested {
+                    // presets are applied before the other items of the attribute, so
+                    // #[shorthand(disable(set), readonly)] is redundant as well
+                    let (presets, items): (Vec<_>, Vec<_>) = data.nested.iter().partition(|item| {
+                        if let NestedMeta::Meta(inner) = item {
+                            AttributesBuilder::is_preset(&inner.to_string())
+                        } else {
+                            false
+                        }
+                    });
+
+                    for item in presets.into_iter().chain(items) {
                         if let NestedMeta::Meta(inner) = &item {
                             // name is for ex. `enable` or `disable`
                             let name = inner.to_string();
@@ -77,47 +201,23 @@ impl Options {
                             // TODO: remove the loop?
                             for field in &Self::FIELDS {
                                 if &name == field {
-                                    if field == &"enable" || field == &"disable" {
-                                        match Attributes::with_meta(result.attributes, field, inner)
-                                        {
-                                            Ok(val) => {
-                                                result.attributes = val;
-                                            }
-                                            Err(err) => {
-                                                errors.push(err);
-                                            }
-                                        }
-                                    } else if field == &"visibility" {
-                                        match syn::parse2::<FieldVisibility>(quote!(#attr)) {
-                                            Ok(value) => {
-                                                result.visibility = value
-                                                    .into_inner()
-                                                    .unwrap_or_else(|| result.vis.clone());
-                                            }
-                                            Err(err) => {
-                                                errors.push(Error::syn(err));
-                                            }
-                                        }
-                                    } else if field == &"rename" {
-                                        match syn::parse2(quote!(#attr)) {
-                                            Ok(attr) => {
-                                                result.rename = attr;
-                                            }
-                                            Err(err) => {
-                                                errors.push(Error::syn(err));
-                                            }
-                                        }
-                                    } else if field == &"verify" {
-                                        match syn::parse2(quote!(#attr)) {
-                                            Ok(attr) => {
-                                                result.verify = attr;
-                                            }
-                                            Err(err) => {
-                                                errors.push(Error::syn(err));
-                                            }
-                                        }
-                                    } else {
-                                        unreachable!(format!("unhandled field: {}", field));
+                                    let handled =
+                                        result.parse_accessor_item(field, attr, inner, &mut errors)
+                                            || result.parse_naming_item(
+                                                field,
+                                                attr,
+                                                inner,
+                                                &mut errors,
+                                            )
+                                            || result.parse_struct_item(
+                                                field,
+                                                attr,
+                                                inner,
+                                                &mut errors,
+                                            );
+
+                                    if !handled {
+                                        unreachable!("unhandled field: {}", field);
                                     }
 
                                     unknown = false;
@@ -129,14 +229,14 @@ impl Options {
                             if unknown {
                                 errors.push(
                                     Error::unknown_field(name.as_str())
-                                        .with_alts(&Self::FIELDS)
+                                        .with_alts(Self::FIELDS)
                                         .with_span(&inner),
                                 );
                             }
                         }
                     }
                 } else {
-                    errors.push(Error::unexpected_meta(&meta).with_alts(&["List"]));
+                    errors.push(Error::unexpected_meta(&meta).with_alts(["List"]));
                     continue;
                 }
             } else {
@@ -151,13 +251,292 @@ impl Options {
             return Err(Error::multiple(errors));
         }
 
-        if result.is_initial {
-            result.is_initial = false;
+        Ok(result)
+    }
+
+    /// Parses the items, that configure the generated accessors, like
+    /// `enable`, `disable`, the presets, `inline`, `visibility`, `doc` and
+    /// `attrs`.
+    ///
+    /// Returns `false`, if the `field` does not belong to this group.
+    fn parse_accessor_item(
+        &mut self,
+        field: &str,
+        attr: &syn::Attribute,
+        inner: &Meta,
+        errors: &mut Vec<Error>,
+    ) -> bool {
+        let result = {
+            if field == "enable" || field == "disable" {
+                Attributes::with_meta(self.attributes, field, inner, &self.configured)
+                    .map(|value| self.attributes = value)
+            } else if AttributesBuilder::is_preset(field) {
+                Attributes::with_preset(self.attributes, inner, &self.configured)
+                    .map(|value| self.attributes = value)
+            } else if field == "inline" {
+                Attributes::with_inline(self.attributes, inner, &self.configured)
+                    .map(|value| self.attributes = value)
+            } else if field == "visibility" {
+                syn::parse2::<FieldVisibility>(quote!(#attr))
+                    .map(|value| self.visibility.update(value, &self.vis))
+                    .map_err(Error::syn)
+            } else if field == "doc" {
+                syn::parse2::<Docs>(quote!(#attr))
+                    .map(|value| self.docs.update(value))
+                    .map_err(Error::syn)
+            } else if field == "attrs" {
+                self.accessor_attrs.parse_attrs(inner)
+            } else {
+                return false;
+            }
+        };
+
+        if let Err(err) = result {
+            errors.push(err);
+        }
+
+        true
+    }
+
+    /// Parses the items, that change the names of the generated functions,
+    /// like `rename`, `rename_all`, `bool_prefix` and `strip`.
+    ///
+    /// Returns `false`, if the `field` does not belong to this group.
+    fn parse_naming_item(
+        &mut self,
+        field: &str,
+        attr: &syn::Attribute,
+        inner: &Meta,
+        errors: &mut Vec<Error>,
+    ) -> bool {
+        let result = {
+            if field == "rename" {
+                syn::parse2(quote!(#attr))
+                    .map(|value| self.rename = value)
+                    .map_err(Error::syn)
+            } else if field == "bool_prefix" {
+                // #[shorthand(bool_prefix = "is_")]
+                if let Meta::NameValue(pair) = inner {
+                    Rename::bool_prefix(&pair.lit).map(|value| self.bool_prefix = value)
+                } else {
+                    Err(Error::unexpected_meta(inner).with_alts(["NameValue"]))
+                }
+            } else if field == "rename_all" {
+                // #[shorthand(rename_all = "camelCase")]
+                if !self.is_initial {
+                    Err(Error::unexpected_field(field).with_span(&inner))
+                } else if let Meta::NameValue(pair) = inner {
+                    Case::from_lit(&pair.lit).map(|case| self.rename_all = Some(case))
+                } else {
+                    Err(Error::unexpected_meta(inner).with_alts(["NameValue"]))
+                }
+            } else if Strip::is_strip(field) {
+                self.strip.update(inner)
+            } else {
+                return false;
+            }
+        };
+
+        if let Err(err) = result {
+            errors.push(err);
+        }
+
+        true
+    }
+
+    /// Parses the items, that can only be used on the struct or only on a
+    /// field, like the derives, `generic`, `default`, `reserved`, the
+    /// selection, `profile` and `verify`.
+    ///
+    /// Returns `false`, if the `field` does not belong to this group.
+    fn parse_struct_item(
+        &mut self,
+        field: &str,
+        attr: &syn::Attribute,
+        inner: &Meta,
+        errors: &mut Vec<Error>,
+    ) -> bool {
+        let result = {
+            if let Some(derive) = Derive::from_name(field) {
+                // #[shorthand(constructor)]
+                if self.is_initial {
+                    self.parse_derive(derive, inner, errors);
+                    Ok(())
+                } else {
+                    Err(Error::unexpected_field(field).with_span(&inner))
+                }
+            } else if field == "generic" {
+                // #[shorthand(generic = "V")]
+                Self::parse_generic(&self.generics, inner)
+                    .map(|generic| self.generic = Some(generic))
+            } else if field == "default" {
+                // the default value of a field, `Default` is implemented
+                // with #[shorthand(default_impl)] on the struct
+                if self.is_initial {
+                    Err(Error::unexpected_field(field).with_span(&inner))
+                } else {
+                    DefaultValue::from_meta(inner).map(|value| self.default = Some(value))
+                }
+            } else if field == "reserved" {
+                // the functions are derived for the entire struct, so
+                // the names can only be reserved on the struct
+                if self.is_initial {
+                    self.reserved.update(inner)
+                } else {
+                    Err(Error::unexpected_field(field).with_span(&inner))
+                }
+            } else if Selection::is_selection(field) {
+                // the fields can only be selected on the struct
+                if self.is_initial {
+                    self.selection.update(inner)
+                } else {
+                    Err(Error::unexpected_field(field).with_span(&inner))
+                }
+            } else if field == "profile" {
+                // the profile is applied in `from_derive_input`, before
+                // the attributes of the struct are parsed.
+                if self.is_initial {
+                    Ok(())
+                } else {
+                    Err(Error::unexpected_field(field).with_span(&inner))
+                }
+            } else if field == "verify" {
+                syn::parse2(quote!(#attr))
+                    .map(|value| self.verify = value)
+                    .map_err(Error::syn)
+            } else {
+                return false;
+            }
+        };
+
+        if let Err(err) = result {
+            errors.push(err);
+        }
+
+        true
+    }
+
+    /// Parses a derive of the struct, like `#[shorthand(constructor)]`,
+    /// `#[shorthand(events(record = "Self::record"))]` or
+    /// `#[shorthand(patch(diff))]`.
+    fn parse_derive(&mut self, derive: Derive, inner: &Meta, errors: &mut Vec<Error>) {
+        match (derive, inner) {
+            (_, Meta::Path(_)) => self.add_derive(derive),
+            (Derive::Events, Meta::List(list)) => {
+                match self.events.update(list) {
+                    Ok(()) => self.add_derive(derive),
+                    Err(err) => errors.push(err),
+                }
+            }
+            (Derive::Patch, Meta::List(list)) => {
+                for item in &list.nested {
+                    match item {
+                        NestedMeta::Meta(Meta::Path(path)) if path.is_ident("diff") => {
+                            self.add_derive(Derive::Diff);
+                        }
+                        NestedMeta::Meta(meta) => {
+                            errors.push(
+                                Error::unknown_field(meta.to_string().as_str())
+                                    .with_alts(["diff"])
+                                    .with_span(meta),
+                            );
+                        }
+                        NestedMeta::Lit(value) => {
+                            errors.push(Error::unexpected_lit(value).with_span(value));
+                        }
+                    }
+                }
+
+                self.add_derive(derive);
+            }
+            (Derive::Events | Derive::Patch, _) => {
+                errors.push(Error::unexpected_meta(inner).with_alts(["Path", "List"]));
+            }
+            _ => errors.push(Error::unexpected_meta(inner).with_alts(["Path"])),
+        }
+    }
+
+    /// Parses `generic = "V"`, the generic must not be declared by the struct.
+    fn parse_generic(generics: &Generics, meta: &Meta) -> Result<Ident, Error> {
+        let value = {
+            match meta {
+                Meta::NameValue(pair) => {
+                    if let Lit::Str(value) = &pair.lit {
+                        value
+                    } else {
+                        return Err(Error::unexpected_lit(&pair.lit).with_alts(["string"]));
+                    }
+                }
+                _ => return Err(Error::unexpected_meta(meta).with_alts(["NameValue"])),
+            }
+        };
+
+        let generic = value.parse::<Ident>().map_err(|_| {
+            Error::custom(format!("`{}` is not a valid identifier", value.value())).with_span(value)
+        })?;
+
+        if generics.type_params().any(|param| param.ident == generic)
+            || generics.const_params().any(|param| param.ident == generic)
+        {
+            return Err(Error::custom(format!(
+                "the generic `{generic}` is already declared by the struct"
+            ))
+            .with_span(value));
+        }
+
+        Ok(generic)
+    }
+
+    /// Returns the attributes of the profile, that is selected with
+    /// `#[shorthand(profile = "name")]`.
+    fn profile<'a>(
+        config: &'a Config,
+        attrs: &[syn::Attribute],
+    ) -> Result<Option<&'a Vec<syn::Attribute>>, Error> {
+        let mut result = None;
+
+        for attr in attrs {
+            if attr.path.to_string() != "shorthand" {
+                continue;
+            }
+
+            // invalid attributes will be reported by `parse_attributes`
+            if let Ok(Meta::List(data)) = attr.parse_meta() {
+                for item in &data.nested {
+                    if let NestedMeta::Meta(Meta::NameValue(value)) = item {
+                        if value.path.to_string() != "profile" {
+                            continue;
+                        }
+
+                        if result.is_some() {
+                            return Err(Error::duplicate_field("profile").with_span(&value));
+                        }
+
+                        if let Lit::Str(name) = &value.lit {
+                            result = Some(config.profile(&name.value()).ok_or_else(|| {
+                                Error::custom(format!("unknown profile `{}`", name.value()))
+                                    .with_span(&name)
+                            })?);
+                        } else {
+                            return Err(Error::unexpected_lit(&value.lit).with_alts(["string"]));
+                        }
+                    }
+                }
+            }
         }
 
         Ok(result)
     }
 
+    /// Returns `true`, if the `derive` is enabled for the struct.
+    pub fn derives(&self, derive: Derive) -> bool { self.derives.contains(&derive) }
+
+    fn add_derive(&mut self, derive: Derive) {
+        if !self.derives(derive) {
+            self.derives.push(derive);
+        }
+    }
+
     pub fn with_attrs<T>(&self, attrs: &T) -> Result<Self, Error>
     where
         for<'a> &'a T: IntoIterator<Item = &'a syn::Attribute>,
@@ -169,23 +548,165 @@ impl Options {
 
 impl Options {
     pub fn from_derive_input(input: &DeriveInput) -> Result<Self, Error> {
-        let result = Self {
+        let config = Config::load().map_err(|e| e.with_span(&input.ident))?;
+
+        Self::with_config(input, &config)
+    }
+
+    /// Parses the attributes of the struct after the defaults and the profile
+    /// of the configuration.
+    fn with_config(input: &DeriveInput, config: &Config) -> Result<Self, Error> {
+        let mut result = Self {
             ident: input.ident.clone(),
             generics: input.generics.clone(),
             vis: input.vis.clone(),
             attrs: Vec::new(),
             data: input.data.clone(),
             forward: Forward::default(),
+            accessor_attrs: AccessorAttributes::default(),
 
-            visibility: FieldVisibility::default()
-                .into_inner()
-                .unwrap_or_else(|| input.vis.clone()),
+            visibility: Visibilities::new(
+                &FieldVisibility::default()
+                    .into_inner()
+                    .unwrap_or_else(|| input.vis.clone()),
+            ),
             attributes: Attributes::default(),
             rename: Rename::default(),
+            strip: Strip::default(),
+            rename_all: None,
+            bool_prefix: None,
             verify: Verify::default(),
+            docs: Docs::default(),
+            selection: Selection::default(),
+            reserved: Reserved::default(),
+            generic: None,
+            derives: Vec::new(),
+            events: Events::default(),
+            default: None,
+            config_path: config.path().cloned(),
+            configured: Vec::new(),
             is_initial: true,
         };
 
-        Ok(Self::parse_attributes(result, &input.attrs)?)
+        // the defaults of the configuration replace the built-in defaults and the
+        // profile replaces the defaults of the struct
+        if !config.defaults().is_empty() {
+            result = Self::parse_attributes(result, config.defaults())?;
+            result.configured = result.attributes.changed(&Attributes::default());
+        }
+
+        if let Some(attrs) = Self::profile(config, &input.attrs)? {
+            result = Self::parse_attributes(result, attrs)?;
+        }
+
+        let mut result = Self::parse_attributes(result, &input.attrs)?;
+        result.is_initial = false;
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use proc_macro2::TokenStream;
+
+    use crate::attributes::Inline;
+
+    fn options(config: &str, input: TokenStream) -> Result<Options, Error> {
+        let config = Config::parse(config).unwrap();
+        let input: DeriveInput = syn::parse2(input).unwrap();
+
+        Options::with_config(&input, &config)
+    }
+
+    const CONFIG: &str = r#"
+        [defaults]
+        disable = ["set"]
+        enable = ["into"]
+        inline = "hint"
+
+        [profile.domain]
+        enable = ["must_use"]
+        visibility = "pub(crate)"
+    "#;
+
+    #[test]
+    fn test_config_defaults() {
+        let options = options(
+            CONFIG,
+            quote!(
+                struct Example;
+            ),
+        )
+        .unwrap();
+
+        assert_eq!(
+            options.attributes,
+            Attributes {
+                set: false,
+                into: true,
+                inline: Inline::Hint,
+                ..Attributes::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_config_profile() {
+        let options = options(
+            CONFIG,
+            quote! {
+                #[shorthand(profile = "domain", enable(set))]
+                struct Example;
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            options.attributes,
+            Attributes {
+                must_use: true,
+                into: true,
+                inline: Inline::Hint,
+                ..Attributes::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_config_defaults_repeated() {
+        // the struct does not have to know the configuration, so repeating one of
+        // its defaults is not redundant
+        let options = options(
+            CONFIG,
+            quote! {
+                #[shorthand(disable(set), enable(into), inline(hint))]
+                struct Example;
+            },
+        )
+        .unwrap();
+
+        assert_eq!(options.attributes.set, false);
+        assert_eq!(options.attributes.into, true);
+        assert_eq!(options.attributes.inline, Inline::Hint);
+    }
+
+    #[test]
+    fn test_config_redundant() {
+        let error = options(
+            CONFIG,
+            quote! {
+                #[shorthand(enable(get))]
+                struct Example;
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            Error::redundant_field("get", Some("enabled")).to_string()
+        );
     }
 }
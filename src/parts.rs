@@ -0,0 +1,126 @@
+//! This module generates the `ExampleParts` struct for a struct `Example` with
+//! `#[shorthand(parts)]`, which has a public field for every field of the
+//! struct. `into_parts` and `from_parts` convert between both structs.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Attribute, Field, Ident};
+
+use crate::options::Options;
+
+pub fn parts_ident(name: &Ident) -> Ident { format_ident!("{}Parts", name) }
+
+/// Returns the attributes of a field, that are also added to the field of the
+/// parts, which are the documentation and `#[cfg(...)]`.
+fn field_attributes(field: &Field) -> Vec<&Attribute> {
+    field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("doc") || attr.path.is_ident("cfg"))
+        .collect()
+}
+
+/// Generates the `ExampleParts` struct.
+pub fn generate(options: &Options, fields: &[(Options, &Field)]) -> TokenStream {
+    let name = &options.ident;
+    let vis = &options.vis;
+    let parts = parts_ident(name);
+    let (impl_generics, _, where_clause) = options.generics.split_for_impl();
+
+    let fields = fields.iter().map(|(_, field)| {
+        let attributes = field_attributes(field);
+        let ident = &field.ident;
+        let ty = &field.ty;
+
+        quote! {
+            #(#attributes)*
+            pub #ident: #ty
+        }
+    });
+
+    let doc = format!(
+        "The fields of [`{name}`], that are returned by `{name}::into_parts` and can be converted \
+         back with `{name}::from_parts`."
+    );
+
+    quote! {
+        #[doc = #doc]
+        #[allow(dead_code)]
+        #vis struct #parts #impl_generics #where_clause {
+            #(#fields),*
+        }
+    }
+}
+
+/// Generates the `into_parts` and `from_parts` functions.
+pub fn functions(options: &Options, fields: &[(Options, &Field)]) -> TokenStream {
+    let name = &options.ident;
+    let vis = &options.vis;
+    let parts = parts_ident(name);
+    let (_, ty_generics, _) = options.generics.split_for_impl();
+
+    // the `#[cfg(...)]` of the fields are needed in the patterns as well
+    let idents = fields
+        .iter()
+        .map(|(_, field)| {
+            let attributes = field.attrs.iter().filter(|attr| attr.path.is_ident("cfg"));
+            let ident = &field.ident;
+
+            quote!(#(#attributes)* #ident)
+        })
+        .collect::<Vec<_>>();
+
+    let into_doc = format!("Consumes `Self` and returns all fields as [`{parts}`].");
+
+    let result = quote!(__result);
+    let verify = &options.verify;
+
+    // the parts can contain any value, so they are verified like in `new`
+    let call = verify.call(&quote!(&#result)).map(|call| quote!(#call;));
+
+    let from_parts = {
+        if let (Some(try_call), Some(error)) = (verify.try_call(&quote!(&#result)), verify.error())
+        {
+            let from_doc = format!(
+                "Creates `Self` from the fields in [`{parts}`] and returns an error, if they are \
+                 invalid."
+            );
+
+            quote! {
+                #[doc = #from_doc]
+                #vis fn from_parts(
+                    parts: #parts #ty_generics,
+                ) -> ::core::result::Result<Self, #error> {
+                    let #parts { #(#idents),* } = parts;
+                    let #result = Self { #(#idents),* };
+                    #call
+                    #try_call?;
+                    ::core::result::Result::Ok(#result)
+                }
+            }
+        } else {
+            let from_doc = format!("Creates `Self` from the fields in [`{parts}`].");
+
+            quote! {
+                #[doc = #from_doc]
+                #vis fn from_parts(parts: #parts #ty_generics) -> Self {
+                    let #parts { #(#idents),* } = parts;
+                    let #result = Self { #(#idents),* };
+                    #call
+                    #result
+                }
+            }
+        }
+    };
+
+    quote! {
+        #[doc = #into_doc]
+        #[must_use]
+        #vis fn into_parts(self) -> #parts #ty_generics {
+            let Self { #(#idents),* } = self;
+            #parts { #(#idents),* }
+        }
+
+        #from_parts
+    }
+}
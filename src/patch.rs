@@ -0,0 +1,263 @@
+//! This module generates the `ExamplePatch` struct for a struct `Example` with
+//! `#[shorthand(patch)]`, which has an `Option` for every field with a setter.
+//! The patch is applied with `apply_patch`, which calls the setters, and
+//! `diff` creates the patch between two values, if it is enabled with
+//! `#[shorthand(patch(diff))]`.
+
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::spanned::Spanned as _;
+use syn::{Attribute, Field, Ident, WherePredicate};
+
+use crate::error::Error;
+use crate::expand::Generator;
+use crate::options::Options;
+
+pub fn patch_ident(name: &Ident) -> Ident { format_ident!("{}Patch", name) }
+
+/// Returns the forwarded attributes of a field, that are added to the field of
+/// the patch, which are the documentation and `#[cfg(...)]`.
+fn field_attributes(options: &Options) -> Vec<&Attribute> {
+    options
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("doc") || attr.path.is_ident("cfg"))
+        .collect()
+}
+
+/// Returns the `#[cfg(...)]` of a field, which are also needed, where the field
+/// of the patch is used.
+fn cfg_attributes(options: &Options) -> Vec<&Attribute> {
+    options
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("cfg"))
+        .collect()
+}
+
+/// Returns the fields, that are part of the patch, which are all fields with a
+/// setter.
+fn patch_fields<'a>(fields: &'a [(Options, &'a Field)]) -> Vec<&'a (Options, &'a Field)> {
+    fields
+        .iter()
+        .filter(|(options, field)| !Generator::is_ignored(options, field) && options.attributes.set)
+        .collect()
+}
+
+/// The struct has to use all generics, but the fields with a generic could be
+/// skipped.
+fn marker(options: &Options) -> Option<TokenStream> {
+    if options.generics.params.is_empty() {
+        None
+    } else {
+        let name = &options.ident;
+        let (_, ty_generics, _) = options.generics.split_for_impl();
+
+        Some(quote!(::core::marker::PhantomData<fn() -> #name #ty_generics>))
+    }
+}
+
+/// Generates the `ExamplePatch` struct.
+pub fn generate(options: &Options, fields: &[(Options, &Field)]) -> TokenStream {
+    let name = &options.ident;
+    let vis = &options.vis;
+    let patch = patch_ident(name);
+    let (impl_generics, ty_generics, where_clause) = options.generics.split_for_impl();
+    let fields = patch_fields(fields);
+
+    let definitions = fields.iter().map(|(options, field)| {
+        let attributes = field_attributes(options);
+        let ident = &field.ident;
+        let ty = &field.ty;
+
+        quote! {
+            #(#attributes)*
+            pub #ident: ::core::option::Option<#ty>
+        }
+    });
+
+    let defaults = fields.iter().map(|(options, field)| {
+        let attributes = cfg_attributes(options);
+        let ident = &field.ident;
+
+        quote! {
+            #(#attributes)*
+            #ident: ::core::option::Option::None
+        }
+    });
+
+    let checks = fields.iter().map(|(options, field)| {
+        let attributes = cfg_attributes(options);
+        let ident = &field.ident;
+
+        quote! {
+            #(#attributes)*
+            {
+                if self.#ident.is_some() {
+                    return false;
+                }
+            }
+        }
+    });
+
+    let marker_field = marker(options).map(|marker| quote!(#[doc(hidden)] pub __marker: #marker,));
+    let marker_value = marker_field
+        .as_ref()
+        .map(|_| quote!(__marker: ::core::marker::PhantomData,));
+
+    let doc = format!(
+        "A partial update of [`{name}`], where every field, that is `Some`, is set by \
+         `{name}::apply_patch`."
+    );
+
+    quote! {
+        #[doc = #doc]
+        #[allow(dead_code)]
+        #vis struct #patch #impl_generics #where_clause {
+            #(#definitions,)*
+            #marker_field
+        }
+
+        impl #impl_generics ::core::default::Default for #patch #ty_generics #where_clause {
+            fn default() -> Self {
+                Self {
+                    #(#defaults,)*
+                    #marker_value
+                }
+            }
+        }
+
+        #[allow(dead_code)]
+        impl #impl_generics #patch #ty_generics #where_clause {
+            /// Returns `true`, if the patch does not change any field.
+            #vis fn is_empty(&self) -> bool {
+                #(#checks)*
+                true
+            }
+        }
+    }
+}
+
+/// Generates the `apply_patch` function.
+pub fn functions(options: &Options, fields: &[(Options, &Field)]) -> Result<TokenStream, Error> {
+    let name = &options.ident;
+    let vis = &options.vis;
+    let patch = patch_ident(name);
+    let (_, ty_generics, _) = options.generics.split_for_impl();
+
+    let mut applications = vec![];
+
+    for (field_options, field) in patch_fields(fields) {
+        let attributes = cfg_attributes(field_options);
+        let ident = &field.ident;
+        let application = Generator::call_setter(field_options, field, None)?;
+
+        applications.push(quote! {
+            #(#attributes)*
+            {
+                if let ::core::option::Option::Some(value) = patch.#ident {
+                    #application
+                }
+            }
+        });
+    }
+
+    let apply_doc = format!(
+        "Sets every field, that is `Some` in the [`{patch}`], with its setter and returns `&mut \
+         Self`."
+    );
+
+    Ok(quote! {
+        #[doc = #apply_doc]
+        #vis fn apply_patch(&mut self, patch: #patch #ty_generics) -> &mut Self {
+            #(#applications)*
+            self
+        }
+    })
+}
+
+/// Returns `true`, if the `ident` is part of the `tokens`.
+fn contains_ident(tokens: TokenStream, ident: &Ident) -> bool {
+    tokens.into_iter().any(|tree| {
+        match tree {
+            TokenTree::Ident(other) => &other == ident,
+            TokenTree::Group(group) => contains_ident(group.stream(), ident),
+            _ => false,
+        }
+    })
+}
+
+/// Generates an `impl` block with `diff`, because the fields of the patch have
+/// to implement `PartialEq` and `Clone`, which is not required by the other
+/// functions.
+pub fn diff(options: &Options, fields: &[(Options, &Field)]) -> TokenStream {
+    let name = &options.ident;
+    let vis = &options.vis;
+    let patch = patch_ident(name);
+    let fields = patch_fields(fields);
+
+    let mut generics = options.generics.clone();
+    let mut differences = vec![];
+
+    // only the generics, that are used by the fields of the patch, have to
+    // implement the traits, the other fields report an error for their type
+    let params = generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .filter(|param| {
+            fields
+                .iter()
+                .any(|(_, field)| contains_ident(field.ty.to_token_stream(), param))
+        })
+        .collect::<Vec<_>>();
+
+    for param in params {
+        let predicate: WherePredicate =
+            syn::parse2(quote!(#param: ::core::cmp::PartialEq + ::core::clone::Clone)).unwrap();
+        generics.make_where_clause().predicates.push(predicate);
+    }
+
+    for (field_options, field) in fields {
+        let attributes = cfg_attributes(field_options);
+        let ident = &field.ident;
+
+        // the error points at the type, if it does not implement the traits
+        let span = field.ty.span();
+        let eq = quote_spanned!(span=> ::core::cmp::PartialEq::eq);
+        let clone = quote_spanned!(span=> ::core::clone::Clone::clone);
+
+        differences.push(quote! {
+            #(#attributes)*
+            #ident: {
+                if #eq(&self.#ident, &other.#ident) {
+                    ::core::option::Option::None
+                } else {
+                    ::core::option::Option::Some(#clone(&other.#ident))
+                }
+            }
+        });
+    }
+
+    let marker_value = marker(options).map(|_| quote!(__marker: ::core::marker::PhantomData,));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let diff_doc = format!(
+        "Returns the [`{patch}`], that changes `self` into `other`. The fields of the patch have to \
+         implement `PartialEq` and `Clone`."
+    );
+
+    quote! {
+        #[allow(dead_code)]
+        #[allow(clippy::all)]
+        impl #impl_generics #name #ty_generics #where_clause {
+            #[doc = #diff_doc]
+            #[must_use]
+            #vis fn diff(&self, other: &Self) -> #patch #ty_generics {
+                #patch {
+                    #(#differences,)*
+                    #marker_value
+                }
+            }
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use proc_macro2::Span;
 use syn::parse::{Parse, ParseStream};
 use syn::spanned::Spanned as _;
-use syn::{Ident, Lit, Meta, NestedMeta, Token};
+use syn::{Ident, Lit, LitStr, Meta, NestedMeta, Token};
 
 use crate::error::Error;
 use crate::parser::parse_shorthand;
@@ -14,9 +14,22 @@ mod kw {
 #[derive(Clone, Debug)]
 pub struct Rename {
     get_format: Format,
+    /// The format of getters for `bool` fields, if it is `None` the
+    /// `get_format` is used.
+    get_bool_format: Option<Format>,
     set_format: Format,
     get_mut_format: Format,
     try_set_format: Format,
+    try_get_mut_format: Format,
+    try_verify_format: Format,
+    push_format: Format,
+    insert_format: Format,
+    reset_format: Format,
+    into_field_format: Format,
+    parse_format: Format,
+    /// The format of the variants of the event enum, which is not derived from
+    /// the `format` of the getter.
+    event_format: Format,
 }
 
 /// Copied from the `syn::Ident` implementation.
@@ -37,29 +50,182 @@ fn is_reserved_ident<T: AsRef<str>>(value: T) -> bool {
     }
 }
 
+/// The case of an identifier, which can be used in a format string like
+/// `{:camel}` or for all functions with `rename_all = "camelCase"`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Case {
+    Camel,
+    Pascal,
+    Upper,
+    Snake,
+}
+
+impl Case {
+    const CASES: [Self; 4] = [Self::Camel, Self::Pascal, Self::Upper, Self::Snake];
+    pub const PLACEHOLDERS: [&'static str; 4] = ["camel", "pascal", "upper", "snake"];
+    pub const RENAME_ALL: [&'static str; 4] = [
+        "camelCase",
+        "PascalCase",
+        "SCREAMING_SNAKE_CASE",
+        "snake_case",
+    ];
+
+    pub fn from_placeholder(value: &str) -> Option<Self> {
+        Self::PLACEHOLDERS
+            .iter()
+            .position(|placeholder| placeholder == &value)
+            .map(|i| Self::CASES[i])
+    }
+
+    /// Parses the value of `rename_all = "camelCase"`.
+    pub fn from_lit(lit: &Lit) -> Result<Self, Error> {
+        if let Lit::Str(value) = lit {
+            Self::RENAME_ALL
+                .iter()
+                .position(|case| case == &value.value())
+                .map(|i| Self::CASES[i])
+                .ok_or_else(|| {
+                    Error::custom(format!(
+                        "unknown case `{}`, expected {}",
+                        value.value(),
+                        Self::RENAME_ALL
+                            .iter()
+                            .map(|case| format!("`{case}`"))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ))
+                    .with_span(value)
+                })
+        } else {
+            Err(Error::unexpected_lit(lit).with_alts(["string"]))
+        }
+    }
+
+    /// Splits the `value` into lowercase words, at each `_` and in front of
+    /// each uppercase letter, that follows a lowercase letter or a digit.
+    fn words(value: &str) -> Vec<String> {
+        let mut result: Vec<String> = vec![];
+        let mut last: Option<char> = None;
+
+        for c in value.chars() {
+            if c == '_' {
+                last = None;
+                continue;
+            }
+
+            let is_boundary = c.is_ascii_uppercase()
+                && matches!(last, Some(last) if last.is_ascii_lowercase() || last.is_ascii_digit());
+
+            if last.is_none() || is_boundary {
+                result.push(String::new());
+            }
+
+            if let Some(word) = result.last_mut() {
+                word.push(c.to_ascii_lowercase());
+            }
+
+            last = Some(c);
+        }
+
+        result
+    }
+
+    /// Converts the `value` into this case. Leading underscores are kept.
+    pub fn apply(self, value: &str) -> String {
+        let prefix = &value[..value.len() - value.trim_start_matches('_').len()];
+        let words = Self::words(value);
+
+        let capitalize = |word: &String| {
+            let mut chars = word.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first.to_ascii_uppercase().to_string() + chars.as_str()
+            })
+        };
+
+        let result = {
+            match self {
+                Self::Camel => {
+                    words
+                        .iter()
+                        .enumerate()
+                        .map(|(i, word)| {
+                            if i == 0 {
+                                word.clone()
+                            } else {
+                                capitalize(word)
+                            }
+                        })
+                        .collect::<String>()
+                }
+                Self::Pascal => words.iter().map(capitalize).collect::<String>(),
+                Self::Upper => words.join("_").to_ascii_uppercase(),
+                Self::Snake => words.join("_"),
+            }
+        };
+
+        format!("{prefix}{result}")
+    }
+}
+
+/// A part of a format string, like `get_` or `{:camel}`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Part {
+    Text(String),
+    Placeholder(Option<Case>),
+}
+
 #[derive(Clone, Debug)]
 pub struct Format(Spanned<String>);
 
 impl Format {
     pub fn new<I: IntoIterator<Item = char>>(input: I, span: Span) -> Result<Self, Error> {
+        let result = input.into_iter().collect::<String>();
         let mut errors = vec![];
 
-        let into_iter = input.into_iter();
-        let mut result = String::with_capacity(into_iter.size_hint().0);
-
-        let mut iterator = into_iter.enumerate().peekable();
-        let mut last_char = None;
+        let mut iterator = result.char_indices().peekable();
+        let mut position = 0;
 
         while let Some((i, c)) = iterator.next() {
+            position += 1;
+
+            // `{}` or `{:case}` will be replaced by the field name
+            if c == '{' {
+                if let Some(end) = result[i..].find('}') {
+                    let placeholder = &result[i + 1..i + end];
+
+                    let valid = placeholder.is_empty()
+                        || matches!(
+                            placeholder.strip_prefix(':'),
+                            Some(case) if Case::from_placeholder(case).is_some()
+                        );
+
+                    if !valid {
+                        errors.push(
+                            Error::custom(format!(
+                                "unknown placeholder `{{{}}}` in format string, expected `{{}}`, \
+                                 {}",
+                                placeholder,
+                                Case::PLACEHOLDERS
+                                    .iter()
+                                    .map(|case| format!("`{{:{case}}}`"))
+                                    .collect::<Vec<_>>()
+                                    .join(", ")
+                            ))
+                            .with_span(&span),
+                        );
+                    }
+
+                    while matches!(iterator.peek(), Some((j, _)) if *j <= i + end) {
+                        iterator.next();
+                        position += 1;
+                    }
+
+                    continue;
+                }
+            }
+
             let valid = {
-                // only `{` followed by an `}` is valid, a single `{` or `}` is invalid!
-                // and those characters are only valid because, `{}` will be replaced by the
-                // field name.
-                if c == '{' && iterator.peek().map_or(false, |s| s.1 == '}')
-                    || c == '}' && last_char.map_or(false, |c| c == '{')
-                {
-                    true
-                } else if i == 0 {
+                if position == 1 {
                     if c == '_' {
                         iterator.peek().is_some()
                     } else {
@@ -74,16 +240,11 @@ impl Format {
             if !valid {
                 errors.push(
                     Error::custom(format!(
-                        "invalid character in format string `{}` at position {}",
-                        c,
-                        i + 1
+                        "invalid character in format string `{c}` at position {position}"
                     ))
                     .with_span(&span),
                 );
             }
-
-            last_char = Some(c);
-            result.push(c);
         }
 
         if is_reserved_ident(&result) {
@@ -105,8 +266,49 @@ impl Format {
         }
     }
 
+    /// The text in front of the first placeholder (`is_` for `is_{}`).
+    fn prefix(&self) -> Option<String> {
+        if let Some(Part::Text(text)) = self.parts().into_iter().next() {
+            Some(text)
+        } else {
+            None
+        }
+    }
+
+    /// Splits the (valid) format string into text and placeholders.
+    fn parts(&self) -> Vec<Part> {
+        let mut result = vec![];
+        let mut rest = self.0.as_str();
+
+        while let Some(start) = rest.find('{') {
+            let end = start + rest[start..].find('}').unwrap_or(rest.len() - start);
+
+            if start > 0 {
+                result.push(Part::Text(rest[..start].to_string()));
+            }
+
+            result.push(Part::Placeholder(
+                rest[start + 1..end]
+                    .strip_prefix(':')
+                    .and_then(Case::from_placeholder),
+            ));
+
+            rest = rest.get(end + 1..).unwrap_or_default();
+        }
+
+        if !rest.is_empty() {
+            result.push(Part::Text(rest.to_string()));
+        }
+
+        result
+    }
+
     pub fn verify_strict(&self) -> Result<(), Error> {
-        if self.0.matches("{}").count() >= 1 {
+        if self
+            .parts()
+            .iter()
+            .any(|part| matches!(part, Part::Placeholder(_)))
+        {
             Ok(())
         } else {
             Err(Error::custom("missing `{}`").with_span(&self.0))
@@ -117,23 +319,184 @@ impl Format {
     pub fn map<F: FnOnce(String) -> String>(self, f: F) -> Self { Self(self.0.map(|s| f(s))) }
 
     pub fn with_ident(&self, replace: &Ident) -> Ident {
+        let replace = replace.to_string();
+
         Ident::new(
             &self
-                .0
-                .split('{')
-                .map(|t| t.replace("}", &replace.to_string()))
+                .parts()
+                .into_iter()
+                .map(|part| {
+                    match part {
+                        Part::Text(text) => text,
+                        Part::Placeholder(None) => replace.clone(),
+                        Part::Placeholder(Some(case)) => case.apply(&replace),
+                    }
+                })
                 .collect::<String>(),
             self.0.span(),
         )
     }
 }
 
+/// Strips a prefix and a suffix from the name of a field, before the names of
+/// the functions are derived from it:
+///
+/// ```text
+/// #[shorthand(strip_prefix = "m_", strip_suffix = "_")]
+/// ```
+///
+/// An empty string disables the stripping (for example for a single field).
+#[derive(Clone, Debug, Default)]
+pub struct Strip {
+    prefix: Option<LitStr>,
+    suffix: Option<LitStr>,
+}
+
+impl Strip {
+    pub const FIELDS: [&'static str; 2] = ["strip_prefix", "strip_suffix"];
+
+    pub fn is_strip(name: &str) -> bool { Self::FIELDS.contains(&name) }
+
+    /// Parses `strip_prefix = "..."` or `strip_suffix = "..."`, which replaces
+    /// the previous value.
+    pub fn update(&mut self, meta: &Meta) -> Result<(), Error> {
+        let value = {
+            if let Meta::NameValue(pair) = meta {
+                if let Lit::Str(value) = &pair.lit {
+                    Some(value.clone()).filter(|value| !value.value().is_empty())
+                } else {
+                    return Err(Error::unexpected_lit(&pair.lit).with_alts(["string"]));
+                }
+            } else {
+                return Err(Error::unexpected_meta(meta).with_alts(["NameValue"]));
+            }
+        };
+
+        if meta.path().is_ident("strip_prefix") {
+            self.prefix = value;
+        } else {
+            self.suffix = value;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the name of the field without the prefix and the suffix.
+    pub fn apply(&self, field: &Ident) -> Result<Ident, Error> {
+        let name = field.to_string();
+        let mut result = name.as_str();
+
+        if let Some(prefix) = &self.prefix {
+            result = result.strip_prefix(&prefix.value()).unwrap_or(result);
+        }
+
+        if let Some(suffix) = &self.suffix {
+            result = result.strip_suffix(&suffix.value()).unwrap_or(result);
+        }
+
+        if result == name {
+            return Ok(field.clone());
+        }
+
+        // the stripped name could be empty or start with a number, but it can be a
+        // keyword, because it could only be a part of the names of the functions,
+        // which are validated after they have been formatted
+        let is_valid = matches!(result.chars().next(), Some(c) if c == '_' || c.is_alphabetic())
+            && result.chars().all(|c| c == '_' || c.is_alphanumeric());
+
+        if is_valid {
+            Ok(Ident::new(result, field.span()))
+        } else {
+            Err(Error::custom(format!(
+                "the name `{result}` of the field `{name}` without the prefix and suffix is not a valid \
+                 identifier"
+            ))
+            .with_span(field))
+        }
+    }
+}
+
 impl Rename {
+    /// Names of `bool` fields with one of these prefixes are not prefixed again
+    /// (`is_enabled` and not `is_is_enabled`).
+    const BOOL_PREFIXES: [&'static str; 2] = ["is_", "has_"];
+    /// The formats, that can be changed with `rename(get = "...")`.
+    const FIELDS: [&'static str; 14] = [
+        "format",
+        "get",
+        "get_bool",
+        "set",
+        "try_set",
+        "get_mut",
+        "try_get_mut",
+        "try_verify",
+        "push",
+        "insert",
+        "reset",
+        "into_field",
+        "parse",
+        "event",
+    ];
+
+    /// Derives the formats of all functions from the `format` of the getter,
+    /// for example `set_{}` from `{}`. The format for `bool` fields is not
+    /// changed.
+    fn set_format(&mut self, format: &Format) {
+        self.get_format = format.clone();
+        self.set_format = format.clone().map(|s| format!("set_{s}"));
+        self.try_set_format = format.clone().map(|s| format!("try_{s}"));
+        self.get_mut_format = format.clone().map(|s| format!("{s}_mut"));
+        self.try_get_mut_format = format.clone().map(|s| format!("try_{s}_mut"));
+        self.try_verify_format = format.clone().map(|s| format!("try_set_{s}"));
+        self.push_format = format.clone().map(|s| format!("push_{s}"));
+        self.insert_format = format.clone().map(|s| format!("insert_{s}"));
+        self.reset_format = format.clone().map(|s| format!("reset_{s}"));
+        self.into_field_format = format.clone().map(|s| format!("into_{s}"));
+        self.parse_format = format.clone().map(|s| format!("set_{s}_from_str"));
+    }
+
+    /// Returns the format `{prefix}{}` of getters for `bool` fields, an empty
+    /// prefix returns `None`.
+    pub fn bool_prefix(lit: &Lit) -> Result<Option<Format>, Error> {
+        if let Lit::Str(value) = lit {
+            if value.value().is_empty() {
+                Ok(None)
+            } else {
+                Ok(Some(Format::new(
+                    value.value().chars().chain("{}".chars()),
+                    value.span(),
+                )?))
+            }
+        } else {
+            Err(Error::unexpected_lit(lit).with_alts(["string"]))
+        }
+    }
+
     // TODO: remove unnecessary Result
     pub fn format_get(&self, value: &Ident) -> Result<Ident, Error> {
         Ok(self.get_format.with_ident(value))
     }
 
+    /// Returns the name of the getter for a `bool` field or `None`, if there
+    /// is no format for `bool` fields. The format of `rename(get_bool = "...")`
+    /// is preferred over the one of `bool_prefix`.
+    pub fn format_get_bool(&self, value: &Ident, bool_prefix: Option<&Format>) -> Option<Ident> {
+        let format = self.get_bool_format.as_ref().or(bool_prefix)?;
+        let name = value.to_string();
+
+        let is_prefixed = Self::BOOL_PREFIXES
+            .iter()
+            .map(ToString::to_string)
+            .chain(format.prefix())
+            .any(|prefix| name.starts_with(&prefix) && name.len() > prefix.len());
+
+        if is_prefixed {
+            Some(value.clone())
+        } else {
+            Some(format.with_ident(value))
+        }
+    }
+
     pub fn format_set(&self, value: &Ident) -> Result<Ident, Error> {
         Ok(self.set_format.with_ident(value))
     }
@@ -145,6 +508,28 @@ impl Rename {
     pub fn format_try_set(&self, value: &Ident) -> Result<Ident, Error> {
         Ok(self.try_set_format.with_ident(value))
     }
+
+    pub fn format_try_get_mut(&self, value: &Ident) -> Result<Ident, Error> {
+        Ok(self.try_get_mut_format.with_ident(value))
+    }
+
+    pub fn format_try_verify(&self, value: &Ident) -> Ident {
+        self.try_verify_format.with_ident(value)
+    }
+
+    pub fn format_push(&self, value: &Ident) -> Ident { self.push_format.with_ident(value) }
+
+    pub fn format_insert(&self, value: &Ident) -> Ident { self.insert_format.with_ident(value) }
+
+    pub fn format_reset(&self, value: &Ident) -> Ident { self.reset_format.with_ident(value) }
+
+    pub fn format_into_field(&self, value: &Ident) -> Ident {
+        self.into_field_format.with_ident(value)
+    }
+
+    pub fn format_parse(&self, value: &Ident) -> Ident { self.parse_format.with_ident(value) }
+
+    pub fn format_event(&self, value: &Ident) -> Ident { self.event_format.with_ident(value) }
 }
 
 impl Parse for Rename {
@@ -182,30 +567,42 @@ impl Parse for Rename {
                                         continue;
                                     }
 
-                                    result.get_format = format.clone();
-                                    result.set_format =
-                                        format.clone().map(|s| format!("set_{}", s));
-                                    result.try_set_format =
-                                        format.clone().map(|s| format!("try_{}", s));
-                                    result.get_mut_format =
-                                        format.clone().map(|s| format!("{}_mut", s));
+                                    result.set_format(&format);
                                 } else if pair.path.is_ident("get") {
                                     result.get_format = format.clone();
+                                } else if pair.path.is_ident("get_bool") {
+                                    result.get_bool_format = Some(format.clone());
                                 } else if pair.path.is_ident("set") {
                                     result.set_format = format.clone();
                                 } else if pair.path.is_ident("try_set") {
                                     result.try_set_format = format.clone();
                                 } else if pair.path.is_ident("get_mut") {
                                     result.get_mut_format = format.clone();
+                                } else if pair.path.is_ident("try_get_mut") {
+                                    result.try_get_mut_format = format.clone();
+                                } else if pair.path.is_ident("try_verify") {
+                                    result.try_verify_format = format.clone();
+                                } else if pair.path.is_ident("push") {
+                                    result.push_format = format.clone();
+                                } else if pair.path.is_ident("insert") {
+                                    result.insert_format = format.clone();
+                                } else if pair.path.is_ident("reset") {
+                                    result.reset_format = format.clone();
+                                } else if pair.path.is_ident("into_field") {
+                                    result.into_field_format = format.clone();
+                                } else if pair.path.is_ident("parse") {
+                                    result.parse_format = format.clone();
+                                } else if pair.path.is_ident("event") {
+                                    result.event_format = format.clone();
                                 } else {
                                     errors.push(
                                         Error::unknown_field(&pair.path.to_string())
+                                            .with_alts(Self::FIELDS)
                                             .with_span(&pair.path),
                                     );
                                 }
                             } else {
-                                errors
-                                    .push(Error::unexpected_meta(&meta).with_alts(&["NameValue"]));
+                                errors.push(Error::unexpected_meta(&meta).with_alts(["NameValue"]));
                             }
                         }
                         // #[shorthand(rename("field"))]
@@ -220,17 +617,14 @@ impl Parse for Rename {
                                 }
                             };
 
-                            result.get_format = format.clone();
-                            result.set_format = format.clone().map(|s| format!("set_{}", s));
-                            result.try_set_format = format.clone().map(|s| format!("try_{}", s));
-                            result.get_mut_format = format.clone().map(|s| format!("{}_mut", s));
+                            result.set_format(&format);
                         }
                     }
                 }
             } else {
                 // #[shorthand(rename)]
                 // #[shorthand(rename = "")]
-                errors.push(Error::unexpected_meta(&meta).with_alts(&["List"]));
+                errors.push(Error::unexpected_meta(&meta).with_alts(["List"]));
             }
         }
 
@@ -242,13 +636,24 @@ impl Parse for Rename {
     }
 }
 
+// the formats are no format strings, so `{:pascal}` is no formatting argument
+#[allow(clippy::literal_string_with_formatting_args)]
 impl Default for Rename {
     fn default() -> Self {
         Self {
             get_format: Format::new("{}".chars(), Span::call_site()).unwrap(),
+            get_bool_format: None,
             set_format: Format::new("set_{}".chars(), Span::call_site()).unwrap(),
             get_mut_format: Format::new("{}_mut".chars(), Span::call_site()).unwrap(),
             try_set_format: Format::new("try_{}".chars(), Span::call_site()).unwrap(),
+            try_get_mut_format: Format::new("try_{}_mut".chars(), Span::call_site()).unwrap(),
+            try_verify_format: Format::new("try_set_{}".chars(), Span::call_site()).unwrap(),
+            push_format: Format::new("push_{}".chars(), Span::call_site()).unwrap(),
+            insert_format: Format::new("insert_{}".chars(), Span::call_site()).unwrap(),
+            reset_format: Format::new("reset_{}".chars(), Span::call_site()).unwrap(),
+            into_field_format: Format::new("into_{}".chars(), Span::call_site()).unwrap(),
+            parse_format: Format::new("set_{}_from_str".chars(), Span::call_site()).unwrap(),
+            event_format: Format::new("{:pascal}".chars(), Span::call_site()).unwrap(),
         }
     }
 }
@@ -259,6 +664,149 @@ mod tests {
     use crate::utils::ErrorExt;
     use pretty_assertions::assert_eq;
 
+    #[test]
+    fn test_case() {
+        assert_eq!(Case::Camel.apply("field_name_2"), "fieldName2".to_string());
+        assert_eq!(Case::Pascal.apply("field_name"), "FieldName".to_string());
+        assert_eq!(Case::Upper.apply("fieldName"), "FIELD_NAME".to_string());
+        assert_eq!(
+            Case::Snake.apply("set_fieldName"),
+            "set_field_name".to_string()
+        );
+        assert_eq!(Case::Camel.apply("_field_name"), "_fieldName".to_string());
+    }
+
+    #[test]
+    fn test_format_case() {
+        let field = Ident::new("field_name", Span::call_site());
+
+        assert_eq!(
+            Format::new("get{:pascal}".chars(), Span::call_site())
+                .unwrap()
+                .with_ident(&field),
+            Ident::new("getFieldName", Span::call_site())
+        );
+        assert_eq!(
+            Format::new("{:camel}_{}".chars(), Span::call_site())
+                .unwrap()
+                .with_ident(&field),
+            Ident::new("fieldName_field_name", Span::call_site())
+        );
+
+        assert_eq!(
+            Format::new("get_{:kebab}".chars(), Span::call_site()).unwrap_err(),
+            Error::custom(
+                "unknown placeholder `{:kebab}` in format string, expected `{}`, `{:camel}`, \
+                 `{:pascal}`, `{:upper}`, `{:snake}`"
+            )
+        );
+        assert!(Format::new("get_{".chars(), Span::call_site()).is_err());
+        assert!(Format::new("{:upper}".chars(), Span::call_site())
+            .unwrap()
+            .verify_strict()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_rename_format() {
+        let field = Ident::new("values", Span::call_site());
+        let rename = syn::parse_str::<Rename>(r#"#[shorthand(rename(format = "my_{}"))]"#).unwrap();
+
+        assert_eq!(rename.format_get(&field).unwrap().to_string(), "my_values");
+        assert_eq!(
+            rename.format_try_get_mut(&field).unwrap().to_string(),
+            "try_my_values_mut"
+        );
+        assert_eq!(rename.format_push(&field).to_string(), "push_my_values");
+        assert_eq!(rename.format_insert(&field).to_string(), "insert_my_values");
+        assert_eq!(rename.format_reset(&field).to_string(), "reset_my_values");
+        assert_eq!(
+            rename.format_into_field(&field).to_string(),
+            "into_my_values"
+        );
+        assert_eq!(
+            rename.format_parse(&field).to_string(),
+            "set_my_values_from_str"
+        );
+        assert_eq!(rename.format_event(&field).to_string(), "Values");
+
+        assert_eq!(
+            Error::syn(
+                syn::parse_str::<Rename>(r#"#[shorthand(rename(append = "add_{}"))]"#).unwrap_err()
+            ),
+            Error::unknown_field("append").with_alts(&Rename::FIELDS)
+        );
+    }
+
+    #[test]
+    fn test_format_get_bool() {
+        let field = |value| Ident::new(value, Span::call_site());
+        let rename = Rename::default();
+
+        assert_eq!(rename.format_get_bool(&field("enabled"), None), None);
+
+        let prefix = Rename::bool_prefix(&syn::parse_str(r#""is_""#).unwrap()).unwrap();
+        let prefix = prefix.as_ref();
+
+        assert_eq!(
+            rename.format_get_bool(&field("enabled"), prefix),
+            Some(field("is_enabled"))
+        );
+        assert_eq!(
+            rename.format_get_bool(&field("is_enabled"), prefix),
+            Some(field("is_enabled"))
+        );
+        assert_eq!(
+            rename.format_get_bool(&field("has_value"), prefix),
+            Some(field("has_value"))
+        );
+        assert_eq!(
+            rename.format_get_bool(&field("is_"), prefix),
+            Some(field("is_is_"))
+        );
+        assert!(Rename::bool_prefix(&syn::parse_str(r#""""#).unwrap())
+            .unwrap()
+            .is_none());
+
+        let rename =
+            syn::parse_str::<Rename>(r#"#[shorthand(rename(get_bool = "can_{}"))]"#).unwrap();
+
+        assert_eq!(
+            rename.format_get_bool(&field("can_write"), prefix),
+            Some(field("can_write"))
+        );
+        assert_eq!(
+            rename.format_get_bool(&field("read"), prefix),
+            Some(field("can_read"))
+        );
+    }
+
+    #[test]
+    fn test_strip() {
+        let mut strip = Strip::default();
+
+        strip
+            .update(&syn::parse_str(r#"strip_prefix = "m_""#).unwrap())
+            .unwrap();
+        strip
+            .update(&syn::parse_str(r#"strip_suffix = "_""#).unwrap())
+            .unwrap();
+
+        let field = |value| Ident::new(value, Span::call_site());
+
+        assert_eq!(strip.apply(&field("m_count_")).unwrap(), field("count"));
+        assert_eq!(strip.apply(&field("count")).unwrap(), field("count"));
+        assert!(strip.apply(&field("m_")).is_err());
+        assert!(strip.apply(&field("m_1")).is_err());
+        assert_eq!(strip.apply(&field("m_type")).unwrap(), field("type"));
+
+        strip
+            .update(&syn::parse_str(r##"strip_prefix = """##).unwrap())
+            .unwrap();
+
+        assert_eq!(strip.apply(&field("m_count_")).unwrap(), field("m_count"));
+    }
+
     #[test]
     fn test_parse_rename() {
         let valid_attributes = &[
@@ -266,6 +814,7 @@ mod tests {
             r#"#[shorthand(rename(get = "abc_{}"))]"#,
             r#"#[shorthand(rename(set = "set_{}"))]"#,
             r#"#[shorthand(rename(get = "xyz_{}", set = "set_{}"))]"#,
+            r#"#[shorthand(rename(push = "add_{}", insert = "put_{}"))]"#,
         ];
 
         for attr in valid_attributes {
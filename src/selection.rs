@@ -0,0 +1,170 @@
+//! This module selects the fields of a struct, that should get functions,
+//! with `#[shorthand(only(a, b))]`, `#[shorthand(except(c))]` and
+//! `#[shorthand(exclude_prefix = "cache_")]`.
+
+use syn::{Ident, Lit, LitStr, Meta, NestedMeta};
+
+use crate::error::Error;
+use crate::utils::PathExt;
+
+#[derive(Debug, Clone, Default)]
+pub struct Selection {
+    only: Option<Vec<Ident>>,
+    except: Vec<Ident>,
+    exclude_prefix: Vec<LitStr>,
+}
+
+impl Selection {
+    pub const FIELDS: [&'static str; 3] = ["only", "except", "exclude_prefix"];
+
+    pub fn is_selection(name: &str) -> bool { Self::FIELDS.contains(&name) }
+
+    /// Parses one of `only(a, b)`, `except(c)` or `exclude_prefix = "cache_"`.
+    pub fn update(&mut self, meta: &Meta) -> Result<(), Error> {
+        let name = meta.path().to_string();
+
+        match meta {
+            Meta::List(list) if name != "exclude_prefix" => {
+                let mut errors = vec![];
+                let mut idents = vec![];
+
+                for nested in &list.nested {
+                    if let NestedMeta::Meta(Meta::Path(path)) = nested {
+                        if let Some(ident) = path.get_ident() {
+                            idents.push(ident.clone());
+                            continue;
+                        }
+                    }
+
+                    match nested {
+                        NestedMeta::Meta(meta) => {
+                            errors.push(Error::unexpected_meta(meta).with_alts(["Path"]));
+                        }
+                        NestedMeta::Lit(value) => {
+                            errors.push(Error::unexpected_lit(value).with_span(value));
+                        }
+                    }
+                }
+
+                if !errors.is_empty() {
+                    return Err(Error::multiple(errors));
+                }
+
+                if name == "only" {
+                    self.only.get_or_insert_with(Vec::new).extend(idents);
+                } else {
+                    self.except.extend(idents);
+                }
+
+                Ok(())
+            }
+            Meta::NameValue(pair) if name == "exclude_prefix" => {
+                if let Lit::Str(value) = &pair.lit {
+                    if value.value().is_empty() {
+                        return Err(Error::custom("the prefix must not be empty").with_span(value));
+                    }
+
+                    self.exclude_prefix.push(value.clone());
+                    Ok(())
+                } else {
+                    Err(Error::unexpected_lit(&pair.lit).with_alts(["string"]))
+                }
+            }
+            _ => {
+                if name == "exclude_prefix" {
+                    Err(Error::unexpected_meta(meta).with_alts(["NameValue"]))
+                } else {
+                    Err(Error::unexpected_meta(meta).with_alts(["List"]))
+                }
+            }
+        }
+    }
+
+    /// Checks, that all fields in `only` and `except` exist and that they are
+    /// not listed twice in the same list.
+    pub fn verify<'a, I>(&self, fields: I) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = &'a Ident>,
+    {
+        let fields = fields.into_iter().collect::<Vec<_>>();
+        let mut errors = vec![];
+
+        for list in &[self.only.as_deref().unwrap_or_default(), &self.except] {
+            let mut seen: Vec<&Ident> = vec![];
+
+            for ident in *list {
+                if !fields.contains(&ident) {
+                    errors.push(
+                        Error::unknown_field(&ident.to_string())
+                            .with_alts(&fields)
+                            .with_span(ident),
+                    );
+                } else if seen.contains(&ident) {
+                    errors.push(Error::duplicate_field(ident.to_string()).with_span(ident));
+                } else {
+                    seen.push(ident);
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::multiple(errors))
+        }
+    }
+
+    /// Returns `true`, if no functions should be generated for the field.
+    pub fn is_excluded(&self, field: &Ident) -> bool {
+        let name = field.to_string();
+
+        matches!(&self.only, Some(only) if !only.contains(field))
+            || self.except.contains(field)
+            || self
+                .exclude_prefix
+                .iter()
+                .any(|prefix| name.starts_with(&prefix.value()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use proc_macro2::Span;
+
+    fn ident(value: &str) -> Ident { Ident::new(value, Span::call_site()) }
+
+    #[test]
+    fn test_is_excluded() {
+        let mut selection = Selection::default();
+
+        selection
+            .update(&syn::parse_str("only(a, b, cache_c)").unwrap())
+            .unwrap();
+        selection
+            .update(&syn::parse_str("except(b)").unwrap())
+            .unwrap();
+        selection
+            .update(&syn::parse_str(r#"exclude_prefix = "cache_""#).unwrap())
+            .unwrap();
+
+        assert!(!selection.is_excluded(&ident("a")));
+        assert!(selection.is_excluded(&ident("b")));
+        assert!(selection.is_excluded(&ident("cache_c")));
+        assert!(selection.is_excluded(&ident("d")));
+    }
+
+    #[test]
+    fn test_verify() {
+        let mut selection = Selection::default();
+        selection
+            .update(&syn::parse_str("except(b, c)").unwrap())
+            .unwrap();
+
+        assert_eq!(
+            selection.verify(&[ident("a"), ident("b")]).unwrap_err(),
+            Error::unknown_field("c").with_alts(&["a", "b"])
+        );
+    }
+}
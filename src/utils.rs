@@ -28,7 +28,7 @@ impl<T: ::core::hash::Hash> ::core::hash::Hash for Spanned<T> {
     where
         H: ::core::hash::Hasher,
     {
-        self.inner.hash(state)
+        self.inner.hash(state);
     }
 }
 
@@ -75,7 +75,7 @@ impl<T> From<T> for Spanned<T> {
     fn from(inner: T) -> Self { Self::new(inner) }
 }
 
-pub(crate) trait AttributeExt {
+pub trait AttributeExt {
     type Target: Sized;
 
     fn from_token_stream(_: TokenStream) -> syn::Result<Self::Target>;
@@ -83,11 +83,11 @@ pub(crate) trait AttributeExt {
     fn from_str(_: &str) -> syn::Result<Self::Target>;
 }
 
-pub(crate) trait PathExt {
+pub trait PathExt {
     fn to_string(&self) -> String;
 }
 
-pub(crate) trait MetaExt {
+pub trait MetaExt {
     fn to_string(&self) -> String;
 }
 
@@ -107,7 +107,7 @@ impl AttributeExt for Attribute {
     }
 }
 
-pub(crate) trait ErrorExt {
+pub trait ErrorExt {
     fn multiple<T>(errors: T) -> syn::Error
     where
         T: IntoIterator<Item = syn::Error>;
@@ -116,7 +116,7 @@ pub(crate) trait ErrorExt {
 impl ErrorExt for syn::Error {
     fn multiple<T>(errors: T) -> syn::Error
     where
-        T: IntoIterator<Item = syn::Error>,
+        T: IntoIterator<Item = Self>,
     {
         let mut errors = errors.into_iter();
         let mut result = errors
@@ -145,7 +145,7 @@ impl PathExt for Path {
     }
 }
 
-pub(crate) trait TypeExt {
+pub trait TypeExt {
     fn path(&self) -> Option<&Path>;
 
     fn arguments(&self) -> Option<Vec<TokenStream>>;
@@ -218,7 +218,7 @@ impl TypeExt for Type {
             Self::Path(ty) => &ty.path,
             // mutable references do not implement copy:
             Self::Reference(ty) => return ty.mutability.is_none(),
-            Self::Tuple(ty) => return !ty.elems.iter().map(|s| s.is_primitive_copy()).any(|e| !e),
+            Self::Tuple(ty) => return !ty.elems.iter().map(TypeExt::is_primitive_copy).any(|e| !e),
             _ => return false,
         };
 
@@ -230,7 +230,7 @@ impl TypeExt for Type {
                     if let PathArguments::AngleBracketed(bracketed) = &last.arguments {
                         let mut result = true;
 
-                        for arg in bracketed.args.iter() {
+                        for arg in &bracketed.args {
                             if let GenericArgument::Type(ty) = arg {
                                 result = ty.is_primitive_copy();
                             } // all other kinds of GenericArgument are ignored for now...
@@ -1,7 +1,7 @@
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens, TokenStreamExt};
 use syn::parse::{Parse, ParseStream};
-use syn::{Lit, Meta, NestedMeta, Path, Token};
+use syn::{Lit, Meta, NestedMeta, Path, Token, Type};
 
 use crate::error::Error;
 use crate::parser::parse_shorthand;
@@ -17,21 +17,78 @@ use crate::parser::parse_shorthand;
 /// ```text
 /// #[shorthand(verify(fn = "Self::path::to::a::function"))]
 /// ```
-#[derive(Debug, PartialEq, Clone)]
+///
+/// A fallible function can be added with `try_fn`, which has to return
+/// `Result<(), error>`. Without a `fn`, the functions, that can not return the
+/// error, call `try_fn` and panic, if it fails:
+///
+/// ```text
+/// #[shorthand(verify(fn = "Self::verify", try_fn = "Self::check", error = "String"))]
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Verify {
     path: Option<Path>,
+    try_path: Option<Path>,
+    error: Option<Type>,
 }
 
 impl Default for Verify {
-    fn default() -> Self { Self { path: None } }
+    fn default() -> Self {
+        Self {
+            path: None,
+            try_path: None,
+            error: None,
+        }
+    }
+}
+
+impl Verify {
+    pub const fn error(&self) -> Option<&Type> { self.error.as_ref() }
+
+    /// Returns `true`, if there is a fallible verify function.
+    pub const fn is_fallible(&self) -> bool { self.try_path.is_some() }
+
+    /// Returns the path of one of the verify functions, if there is one.
+    pub fn path(&self) -> Option<&Path> { self.path.as_ref().or(self.try_path.as_ref()) }
+
+    /// Calls the infallible verify function with `receiver`.
+    pub fn call(&self, receiver: &TokenStream) -> Option<TokenStream> {
+        self.path.as_ref().map(|path| quote!(#path(#receiver)))
+    }
+
+    /// Verifies `receiver` in a function, that can not return the error of
+    /// `try_fn`. If there is no infallible function, `try_fn` is called and
+    /// panics, if it fails.
+    pub fn call_or_panic(&self, receiver: &TokenStream) -> Option<TokenStream> {
+        if let Some(call) = self.call(receiver) {
+            return Some(quote!(#call;));
+        }
+
+        self.try_path.as_ref().map(|path| {
+            let message = format!(
+                "`{}` failed to verify the value",
+                quote!(#path).to_string().replace(' ', "")
+            );
+
+            quote! {
+                if ::core::result::Result::is_err(&#path(#receiver)) {
+                    ::core::panic!(#message);
+                }
+            }
+        })
+    }
+
+    /// Calls the fallible verify function with `receiver`, which evaluates to
+    /// a `Result<(), error>`.
+    pub fn try_call(&self, receiver: &TokenStream) -> Option<TokenStream> {
+        self.try_path.as_ref().map(|path| quote!(#path(#receiver)))
+    }
 }
 
 impl ToTokens for Verify {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        if let Some(path) = &self.path {
-            tokens.append_all(quote! {
-                #path (&self);
-            });
+        if let Some(call) = self.call_or_panic(&quote!(&self)) {
+            tokens.append_all(call);
         }
     }
 }
@@ -48,42 +105,61 @@ impl Parse for Verify {
                 }
 
                 if let Meta::List(list) = meta {
-                    let nested = list
-                        .nested
-                        .first()
-                        .ok_or_else(|| syn::Error::new_spanned(&list, "expected items in list"))?;
-
-                    match &nested {
-                        NestedMeta::Meta(Meta::NameValue(name_value)) => {
-                            if !name_value.path.is_ident("fn") {
-                                return Err(syn::Error::new_spanned(
-                                    &name_value.path,
-                                    "expected `fn`",
-                                ));
-                            }
+                    if list.nested.is_empty() {
+                        return Err(syn::Error::new_spanned(&list, "expected items in list"));
+                    }
+
+                    let mut result = Self::default();
 
-                            if let Lit::Str(str_path) = &name_value.lit {
-                                return Ok(Self {
-                                    path: Some(str_path.parse_with(Path::parse_mod_style)?),
-                                });
-                            } else {
-                                return Err(Error::unexpected_lit(&name_value.lit)
-                                    .with_alts(&["string"])
+                    for nested in &list.nested {
+                        match &nested {
+                            NestedMeta::Meta(Meta::NameValue(name_value)) => {
+                                let str_value = {
+                                    if let Lit::Str(str_value) = &name_value.lit {
+                                        str_value
+                                    } else {
+                                        return Err(Error::unexpected_lit(&name_value.lit)
+                                            .with_alts(["string"])
+                                            .into());
+                                    }
+                                };
+
+                                if name_value.path.is_ident("fn") {
+                                    result.path =
+                                        Some(str_value.parse_with(Path::parse_mod_style)?);
+                                } else if name_value.path.is_ident("try_fn") {
+                                    result.try_path =
+                                        Some(str_value.parse_with(Path::parse_mod_style)?);
+                                } else if name_value.path.is_ident("error") {
+                                    result.error = Some(str_value.parse()?);
+                                } else {
+                                    return Err(syn::Error::new_spanned(
+                                        &name_value.path,
+                                        "expected `fn`, `try_fn` or `error`",
+                                    ));
+                                }
+                            }
+                            NestedMeta::Meta(meta) => {
+                                return Err(Error::unexpected_meta(meta)
+                                    .with_alts(["NameValue"])
                                     .into());
                             }
+                            NestedMeta::Lit(lit) => {
+                                return Err(Error::unexpected_lit(lit).into());
+                            }
                         }
-                        NestedMeta::Meta(meta) => {
-                            return Err(Error::unexpected_meta(meta)
-                                .with_alts(&["NameValue"])
-                                .into());
-                        }
-                        NestedMeta::Lit(lit) => {
-                            return Err(Error::unexpected_lit(lit).into());
-                        }
                     }
-                } else {
-                    return Err(Error::unexpected_meta(&meta).with_alts(&["List"]).into());
+
+                    if result.try_path.is_some() && result.error.is_none() {
+                        return Err(syn::Error::new_spanned(
+                            &list,
+                            "`try_fn` requires the `error` type it returns",
+                        ));
+                    }
+
+                    return Ok(result);
                 }
+                return Err(Error::unexpected_meta(&meta).with_alts(["List"]).into());
             }
         }
 
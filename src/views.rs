@@ -0,0 +1,239 @@
+//! This module generates the view structs `ExampleRef` and `ExampleMut` for a
+//! struct `Example` with `#[shorthand(views)]`. A view has a reference to every
+//! field, that is not skipped, so multiple fields can be borrowed at the same
+//! time, which is not possible with the getters, because they borrow the
+//! entire struct.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Attribute, Field, GenericParam, Generics, Ident, LifetimeDef, Visibility};
+
+use crate::expand::Generator;
+use crate::guard::generic_arguments;
+use crate::options::Options;
+
+pub fn ref_ident(name: &Ident) -> Ident { format_ident!("{}Ref", name) }
+
+pub fn mut_ident(name: &Ident) -> Ident { format_ident!("{}Mut", name) }
+
+/// Extends the generics of the struct with the lifetime `'__view`.
+fn extend_generics(generics: &Generics) -> Generics {
+    let mut result = generics.clone();
+    let lifetime: LifetimeDef = syn::parse2(quote!('__view)).unwrap();
+
+    result.params.insert(0, GenericParam::Lifetime(lifetime));
+
+    result
+}
+
+/// Returns the forwarded attributes of a field, that are added to the field of
+/// a view, which are the documentation and `#[cfg(...)]`.
+fn field_attributes(options: &Options) -> Vec<&Attribute> {
+    options
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("doc") || attr.path.is_ident("cfg"))
+        .collect()
+}
+
+/// A field of a view.
+struct ViewField<'a> {
+    attributes: Vec<&'a Attribute>,
+    visibility: &'a Visibility,
+    ident: &'a Option<Ident>,
+    ty: TokenStream,
+    value: TokenStream,
+}
+
+impl ViewField<'_> {
+    fn definition(&self) -> TokenStream {
+        let Self {
+            attributes,
+            visibility,
+            ident,
+            ty,
+            ..
+        } = self;
+
+        quote! {
+            #(#attributes)*
+            #visibility #ident: #ty
+        }
+    }
+
+    fn value(&self) -> TokenStream {
+        let ident = &self.ident;
+        let value = &self.value;
+        let attributes = self
+            .attributes
+            .iter()
+            .filter(|attr| attr.path.is_ident("cfg"));
+
+        quote! {
+            #(#attributes)*
+            #ident: #value
+        }
+    }
+}
+
+/// Returns the fields of the `ExampleRef`, which are all fields with a getter.
+fn ref_fields<'a>(fields: &'a [(Options, &Field)]) -> Vec<ViewField<'a>> {
+    fields
+        .iter()
+        .filter(|(options, field)| !Generator::is_ignored(options, field) && options.attributes.get)
+        .map(|(options, field)| {
+            let ident = &field.ident;
+            let ty = &field.ty;
+
+            ViewField {
+                attributes: field_attributes(options),
+                visibility: options.visibility.get(),
+                ident,
+                ty: quote!(&'__view #ty),
+                value: quote!(&self.#ident),
+            }
+        })
+        .collect()
+}
+
+/// Returns the fields of the `ExampleMut`. Only fields with a mutable getter,
+/// that has no guard and no verify function, are borrowed mutably, because
+/// the view would bypass them. The other fields with a getter or a setter are
+/// borrowed immutably.
+fn mut_fields<'a>(fields: &'a [(Options, &Field)]) -> Vec<ViewField<'a>> {
+    fields
+        .iter()
+        .filter(|(options, field)| !Generator::is_ignored(options, field))
+        .filter_map(|(options, field)| {
+            let ident = &field.ident;
+            let ty = &field.ty;
+            let attributes = &options.attributes;
+
+            let (visibility, ty, value) = {
+                if attributes.get_mut && !attributes.guard && options.verify.path().is_none() {
+                    (
+                        options.visibility.get_mut(),
+                        quote!(&'__view mut #ty),
+                        quote!(&mut self.#ident),
+                    )
+                } else {
+                    let visibility = {
+                        if attributes.get {
+                            options.visibility.get()
+                        } else if attributes.get_mut {
+                            options.visibility.get_mut()
+                        } else if attributes.set {
+                            options.visibility.set()
+                        } else {
+                            return None;
+                        }
+                    };
+
+                    (visibility, quote!(&'__view #ty), quote!(&self.#ident))
+                }
+            };
+
+            Some(ViewField {
+                attributes: field_attributes(options),
+                visibility,
+                ident,
+                ty,
+                value,
+            })
+        })
+        .collect()
+}
+
+/// Generates the `ExampleRef` and the `ExampleMut` struct.
+pub fn generate(options: &Options, fields: &[(Options, &Field)]) -> TokenStream {
+    let name = &options.ident;
+    let vis = &options.vis;
+    let generics = extend_generics(&options.generics);
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, struct_generics, _) = options.generics.split_for_impl();
+
+    // the generics would be unused, if all fields with a generic are skipped
+    let marker = quote!(__marker: ::core::marker::PhantomData<&'__view #name #struct_generics>);
+
+    let ref_view = ref_ident(name);
+    let ref_fields = ref_fields(fields)
+        .iter()
+        .map(ViewField::definition)
+        .collect::<Vec<_>>();
+
+    let mut_view = mut_ident(name);
+    let mut_fields = mut_fields(fields)
+        .iter()
+        .map(ViewField::definition)
+        .collect::<Vec<_>>();
+
+    let ref_doc = format!(
+        "References to the fields of [`{name}`], that are returned by `{name}::fields_ref`."
+    );
+    let mut_doc = format!(
+        "Mutable references to the fields of [`{name}`], that are returned by \
+         `{name}::fields_mut`. Fields without a mutable getter or with a guard or a verify \
+         function are borrowed immutably."
+    );
+
+    quote! {
+        #[doc = #ref_doc]
+        #[allow(dead_code)]
+        #vis struct #ref_view #impl_generics #where_clause {
+            #(#ref_fields,)*
+            #marker
+        }
+
+        #[doc = #mut_doc]
+        #[allow(dead_code)]
+        #vis struct #mut_view #impl_generics #where_clause {
+            #(#mut_fields,)*
+            #marker
+        }
+    }
+}
+
+/// Generates the `fields_ref` and `fields_mut` functions.
+pub fn functions(options: &Options, fields: &[(Options, &Field)]) -> TokenStream {
+    let name = &options.ident;
+    let vis = &options.vis;
+    // the views borrow `self` for `'_`
+    let arguments = generic_arguments(&options.generics);
+
+    let ref_view = ref_ident(name);
+    let ref_values = ref_fields(fields)
+        .iter()
+        .map(ViewField::value)
+        .collect::<Vec<_>>();
+
+    let mut_view = mut_ident(name);
+    let mut_values = mut_fields(fields)
+        .iter()
+        .map(ViewField::value)
+        .collect::<Vec<_>>();
+
+    let ref_doc =
+        format!("Returns references to all fields, that have a getter, as [`{ref_view}`].");
+    let mut_doc = format!(
+        "Returns mutable references to all fields, that have a mutable getter, as [`{mut_view}`]."
+    );
+
+    quote! {
+        #[doc = #ref_doc]
+        #[must_use]
+        #vis fn fields_ref(&self) -> #ref_view<'_, #(#arguments),*> {
+            #ref_view {
+                #(#ref_values,)*
+                __marker: ::core::marker::PhantomData,
+            }
+        }
+
+        #[doc = #mut_doc]
+        #vis fn fields_mut(&mut self) -> #mut_view<'_, #(#arguments),*> {
+            #mut_view {
+                #(#mut_values,)*
+                __marker: ::core::marker::PhantomData,
+            }
+        }
+    }
+}
@@ -1,7 +1,9 @@
 //! This module is for the visibility attribute
 
+use proc_macro2::TokenStream;
+use syn::ext::IdentExt;
 use syn::parse::{Parse, ParseStream};
-use syn::{LitStr, Visibility};
+use syn::{Ident, LitStr, NestedMeta, Token, Visibility};
 
 use crate::error::Error;
 use crate::parser::parse_shorthand;
@@ -11,19 +13,73 @@ mod kw {
     syn::custom_keyword!(inherit);
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum FieldVisibility {
     Visible(Visibility),
     Inherit,
+    /// The visibility of individual functions:
+    ///
+    /// ```text
+    /// #[shorthand(visibility(get = "pub", set = "pub(crate)"))]
+    /// ```
+    Accessors(Vec<(Ident, Visibility)>),
 }
 
 impl FieldVisibility {
     pub fn into_inner(self) -> Option<Visibility> {
         match self {
             Self::Visible(vis) => Some(vis),
-            Self::Inherit => None,
+            Self::Inherit | Self::Accessors(_) => None,
         }
     }
+
+    /// Parses either a [`LitStr`] like `"pub(crate)"` or `inherit`.
+    fn parse_value(input: ParseStream) -> syn::Result<Self> {
+        let lookahead = input.lookahead1();
+
+        if let Ok(lit_str) = input.parse::<LitStr>() {
+            Ok(Self::Visible(lit_str.parse()?))
+        } else if lookahead.peek(kw::inherit) {
+            input.parse::<kw::inherit>()?;
+            Ok(Self::Inherit)
+        } else {
+            Err(Error::custom("expected literal or `inherit`")
+                .with_span(&lookahead.error().span())
+                .into())
+        }
+    }
+
+    /// Parses `get = "pub", set = "pub(crate)"`.
+    fn parse_accessors(input: ParseStream) -> syn::Result<Self> {
+        let mut result = vec![];
+
+        while !input.is_empty() {
+            let ident = input.call(Ident::parse_any)?;
+
+            if !Visibilities::ACCESSORS
+                .iter()
+                .any(|accessor| ident == accessor)
+            {
+                return Err(Error::unknown_field(&ident.to_string())
+                    .with_alts(Visibilities::ACCESSORS)
+                    .with_span(&ident)
+                    .into());
+            }
+
+            input.parse::<Token![=]>()?;
+            // `inherit` is not allowed here, because `get = inherit` is not a valid
+            // `syn::Meta`
+            result.push((ident, input.parse::<LitStr>()?.parse()?));
+
+            if input.is_empty() {
+                break;
+            }
+
+            input.parse::<Token![,]>()?;
+        }
+
+        Ok(Self::Accessors(result))
+    }
 }
 
 impl Parse for FieldVisibility {
@@ -32,6 +88,7 @@ impl Parse for FieldVisibility {
     /// ```text
     /// #[shorthand(visibility("pub"))]
     /// #[shorthand(visibility("pub(crate)"))]
+    /// #[shorthand(visibility(get = "pub", set = "pub(crate)"))]
     /// ```
     ///
     /// # Note
@@ -44,23 +101,90 @@ impl Parse for FieldVisibility {
     /// ```
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let sub = parse_shorthand(input)?;
+
+        // skip other attributes like `#[shorthand(enable(get_mut), visibility(..))]`
+        while !sub.peek(kw::visibility) {
+            sub.parse::<NestedMeta>()?;
+            sub.parse::<Token![,]>()?;
+        }
+
         sub.parse::<kw::visibility>()?;
         let content;
         syn::parenthesized!(content in sub);
 
-        let lookahead = content.lookahead1();
+        let result = {
+            if content.peek(Ident::peek_any) && content.peek2(Token![=]) {
+                Self::parse_accessors(&content)?
+            } else {
+                Self::parse_value(&content)?
+            }
+        };
 
-        if let Ok(lit_str) = content.parse::<LitStr>() {
-            Ok(Self::Visible(lit_str.parse()?))
-        } else if lookahead.peek(kw::inherit) {
-            content.parse::<kw::inherit>()?;
-            Ok(Self::Inherit)
-        } else {
-            Err(Error::custom("expected literal or `inherit`")
-                .with_span(&lookahead.error().span())
-                .into())
+        // ignore the remaining attributes
+        sub.parse::<TokenStream>()?;
+
+        Ok(result)
+    }
+}
+
+/// The visibilities of all functions, that are derived for a field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Visibilities {
+    get: Visibility,
+    set: Visibility,
+    try_set: Visibility,
+    get_mut: Visibility,
+    try_get_mut: Visibility,
+}
+
+impl Visibilities {
+    pub const ACCESSORS: [&'static str; 5] = ["get", "set", "try_set", "get_mut", "try_get_mut"];
+
+    pub fn new(visibility: &Visibility) -> Self {
+        Self {
+            get: visibility.clone(),
+            set: visibility.clone(),
+            try_set: visibility.clone(),
+            get_mut: visibility.clone(),
+            try_get_mut: visibility.clone(),
         }
     }
+
+    /// Applies the parsed [`FieldVisibility`], where `inherit` is the
+    /// visibility of the struct.
+    pub fn update(&mut self, value: FieldVisibility, inherit: &Visibility) {
+        match value {
+            FieldVisibility::Visible(vis) => *self = Self::new(&vis),
+            FieldVisibility::Inherit => *self = Self::new(inherit),
+            FieldVisibility::Accessors(accessors) => {
+                for (ident, vis) in accessors {
+                    if ident == "get" {
+                        self.get = vis;
+                    } else if ident == "set" {
+                        self.set = vis;
+                    } else if ident == "try_set" {
+                        self.try_set = vis;
+                    } else if ident == "get_mut" {
+                        self.get_mut = vis;
+                    } else if ident == "try_get_mut" {
+                        self.try_get_mut = vis;
+                    } else {
+                        unreachable!("unknown accessor `{}`", ident);
+                    }
+                }
+            }
+        }
+    }
+
+    pub const fn get(&self) -> &Visibility { &self.get }
+
+    pub const fn set(&self) -> &Visibility { &self.set }
+
+    pub const fn try_set(&self) -> &Visibility { &self.try_set }
+
+    pub const fn get_mut(&self) -> &Visibility { &self.get_mut }
+
+    pub const fn try_get_mut(&self) -> &Visibility { &self.try_get_mut }
 }
 
 impl Default for FieldVisibility {
@@ -84,4 +208,29 @@ mod tests {
 
         // TODO: add more tests, for example what should fail
     }
+
+    #[test]
+    fn test_parse_accessors() {
+        let mut visibilities = Visibilities::new(&syn::parse_str("pub").unwrap());
+
+        visibilities.update(
+            syn::parse2::<FieldVisibility>(quote!(#[shorthand(visibility(
+                get = "pub(crate)",
+                get_mut = "pub(super)"
+            ))]))
+            .unwrap(),
+            &syn::parse_str("pub(self)").unwrap(),
+        );
+
+        assert_eq!(visibilities.get(), &syn::parse_str("pub(crate)").unwrap());
+        assert_eq!(visibilities.set(), &syn::parse_str("pub").unwrap());
+        assert_eq!(
+            visibilities.get_mut(),
+            &syn::parse_str("pub(super)").unwrap()
+        );
+
+        assert!(
+            syn::parse2::<FieldVisibility>(quote!(#[shorthand(visibility(gett = "pub"))])).is_err()
+        );
+    }
 }
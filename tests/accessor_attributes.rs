@@ -0,0 +1,28 @@
+#![allow(dead_code)]
+use shorthand::ShortHand;
+
+#[derive(ShortHand, Default)]
+#[shorthand(enable(get_mut))]
+struct Example {
+    #[shorthand_get(must_use, cfg(not(any())))]
+    #[shorthand_set(allow(unused_variables))]
+    value: usize,
+    #[shorthand_get_mut(cfg(any()))]
+    other: String,
+}
+
+impl Example {
+    // `other_mut` is not derived, because of `#[cfg(any())]`
+    fn other_mut(&mut self) -> &mut String { &mut self.other }
+}
+
+#[test]
+fn test_accessor_attributes() {
+    let mut example = Example::default();
+
+    example.set_value(1);
+    example.other_mut().push_str("other");
+
+    assert_eq!(example.value(), 1);
+    assert_eq!(example.other(), &"other".to_string());
+}
@@ -0,0 +1,48 @@
+#![allow(dead_code)]
+use std::cell::Cell;
+use std::panic::Location;
+
+use shorthand::ShortHand;
+
+// `#[track_caller]` is added to every setter, so `verify` sees the line of the
+// call to the setter
+#[derive(ShortHand, Default)]
+#[shorthand(attrs(set = "#[track_caller]"), verify(fn = "Self::verify"))]
+struct Example {
+    value: usize,
+    #[shorthand(attrs(
+        get = "#[must_use] #[doc(alias = \"title\")]",
+        set = "#[doc(alias = \"put_name\")]"
+    ))]
+    name: String,
+    #[shorthand(enable(skip))]
+    caller: Cell<u32>,
+}
+
+impl Example {
+    #[track_caller]
+    fn verify(&self) { self.caller.set(Location::caller().line()); }
+}
+
+#[test]
+fn test_attrs_struct() {
+    let mut example = Example::default();
+
+    let line = line!() + 1;
+    example.set_value(1);
+
+    assert_eq!(example.value(), 1);
+    assert_eq!(example.caller.get(), line);
+}
+
+#[test]
+fn test_attrs_field() {
+    let mut example = Example::default();
+
+    // the attributes of the field are added to the ones of the struct
+    let line = line!() + 1;
+    example.set_name("name".to_string());
+
+    assert_eq!(example.name(), &"name".to_string());
+    assert_eq!(example.caller.get(), line);
+}
@@ -0,0 +1,69 @@
+#![allow(dead_code)]
+use shorthand::ShortHand;
+
+#[derive(ShortHand, Default)]
+#[shorthand(bool_prefix = "is_")]
+struct Example {
+    enabled: bool,
+    is_visible: bool,
+    has_value: bool,
+    #[shorthand(bool_prefix = "", rename(get = "get_{}"))]
+    renamed: bool,
+    #[shorthand(rename(get_bool = "can_{}"))]
+    write: bool,
+    #[shorthand(bool_prefix = "")]
+    plain: bool,
+    value: usize,
+}
+
+// `rename(...)` does not replace the format of `bool_prefix`
+#[derive(ShortHand, Default)]
+#[shorthand(bool_prefix = "is_", rename(set = "put_{}"))]
+struct PrefixFirst {
+    enabled: bool,
+    #[shorthand(rename(get = "get_{}"))]
+    visible: bool,
+    value: usize,
+}
+
+#[derive(ShortHand, Default)]
+#[shorthand(rename(set = "put_{}"), bool_prefix = "is_")]
+struct RenameFirst {
+    enabled: bool,
+    value: usize,
+}
+
+#[test]
+fn test_bool_prefix_with_rename() {
+    let mut first = PrefixFirst::default();
+    first.put_enabled(true).set_visible(true).put_value(1);
+
+    assert_eq!(first.is_enabled(), true);
+    assert_eq!(first.is_visible(), true);
+    assert_eq!(first.value(), 1);
+
+    let mut second = RenameFirst::default();
+    second.put_enabled(true).put_value(1);
+
+    assert_eq!(second.is_enabled(), true);
+    assert_eq!(second.value(), 1);
+}
+
+#[test]
+fn test_bool_prefix() {
+    let mut example = Example::default();
+
+    example
+        .set_enabled(true)
+        .set_is_visible(true)
+        .set_write(true)
+        .set_renamed(true);
+
+    assert_eq!(example.is_enabled(), true);
+    assert_eq!(example.is_visible(), true);
+    assert_eq!(example.has_value(), false);
+    assert_eq!(example.get_renamed(), true);
+    assert_eq!(example.can_write(), true);
+    assert_eq!(example.plain(), false);
+    assert_eq!(example.value(), 0);
+}
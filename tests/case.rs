@@ -0,0 +1,45 @@
+#![allow(dead_code)]
+#![deny(non_snake_case)]
+use shorthand::ShortHand;
+
+#[derive(ShortHand, Default)]
+#[shorthand(rename_all = "camelCase", enable(get_mut, collection_magic))]
+struct Camel {
+    field_name: usize,
+    values: Vec<usize>,
+    #[shorthand(disable(rename))]
+    snake_case: usize,
+}
+
+#[derive(ShortHand, Default)]
+struct Format {
+    #[shorthand(rename(format = "{:pascal}"))]
+    pascal_case: usize,
+    #[shorthand(rename(get = "get_{:upper}", set = "put_{:snake}"))]
+    upper_case: usize,
+    #[shorthand(rename(get = "{:camel}"))]
+    camel_case: usize,
+}
+
+#[test]
+fn test_rename_all() {
+    let mut camel = Camel::default();
+
+    camel.setFieldName(1).pushValues(2).set_snake_case(3);
+    *camel.fieldNameMut() += 1;
+
+    assert_eq!(camel.fieldName(), 2);
+    assert_eq!(camel.values(), &vec![2]);
+    assert_eq!(camel.snake_case(), 3);
+}
+
+#[test]
+fn test_format_case() {
+    let mut format = Format::default();
+
+    format.set_PascalCase(1).put_upper_case(2).set_camel_case(3);
+
+    assert_eq!(format.PascalCase(), 1);
+    assert_eq!(format.get_UPPER_CASE(), 2);
+    assert_eq!(format.camelCase(), 3);
+}
@@ -0,0 +1,2 @@
+//! The tests of this crate are in `tests/`, where the derive reads the
+//! `shorthand.toml` of this crate.
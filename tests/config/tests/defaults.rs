@@ -0,0 +1,36 @@
+#![allow(dead_code)]
+use shorthand::ShortHand;
+
+#[derive(ShortHand, Default)]
+pub struct Example {
+    value: usize,
+    // the setter is disabled by the defaults
+    #[shorthand(enable(set))]
+    other: usize,
+    enabled: bool,
+}
+
+// repeating the defaults of the configuration is not redundant
+#[derive(ShortHand, Default)]
+#[shorthand(disable(set), bool_prefix = "is_")]
+pub struct Repeated {
+    enabled: bool,
+}
+
+#[test]
+fn test_defaults() {
+    let mut example = Example::default();
+
+    example.set_other(1);
+
+    assert_eq!(example.value(), 0);
+    assert_eq!(example.other(), 1);
+    assert_eq!(example.is_enabled(), false);
+}
+
+#[test]
+fn test_defaults_repeated() {
+    let repeated = Repeated::default();
+
+    assert_eq!(repeated.is_enabled(), false);
+}
@@ -0,0 +1,35 @@
+#![allow(dead_code)]
+use shorthand::ShortHand;
+
+#[derive(ShortHand, Default)]
+#[shorthand(profile = "domain")]
+struct Domain {
+    value: usize,
+    #[shorthand(enable(primitive_copy))]
+    copied: usize,
+}
+
+#[derive(ShortHand, Default)]
+#[shorthand(profile = "internal", enable(get_mut))]
+struct ReadOnly {
+    value: String,
+}
+
+#[test]
+fn test_profile() {
+    let mut domain = Domain::default();
+
+    domain.set_value(1_usize).set_copied(2_usize);
+
+    assert_eq!(domain.value(), &1);
+    assert_eq!(domain.copied(), 2);
+}
+
+#[test]
+fn test_profile_with_attributes() {
+    let mut readonly = ReadOnly::default();
+
+    readonly.value_mut().push_str("value");
+
+    assert_eq!(readonly.value(), &"value".to_string());
+}
@@ -0,0 +1,119 @@
+#![allow(dead_code)]
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use shorthand::ShortHand;
+
+#[derive(ShortHand, Debug, PartialEq)]
+#[shorthand(constructor)]
+struct Example {
+    #[shorthand(enable(into))]
+    name: String,
+    #[shorthand(enable(strip_option))]
+    value: Option<usize>,
+    #[shorthand(enable(into))]
+    other: Option<String>,
+    #[shorthand(default)]
+    cache: Vec<u8>,
+    #[shorthand(default = "42")]
+    answer: u8,
+    #[shorthand(enable(skip))]
+    skipped: bool,
+}
+
+#[derive(ShortHand, Debug, PartialEq)]
+#[shorthand(constructor, enable(const_fn))]
+struct Point {
+    x: i32,
+    y: i32,
+    #[shorthand(default = "0")]
+    z: i32,
+    unit: PhantomData<i32>,
+}
+
+// fields, that are excluded from the accessors, are still arguments
+#[derive(ShortHand, Debug, PartialEq)]
+#[shorthand(constructor, except(secret), enable(ignore_underscore))]
+struct Selected {
+    name: String,
+    secret: String,
+    _private: usize,
+}
+
+const ORIGIN: Point = Point::new(0, 0);
+
+#[derive(ShortHand, Debug)]
+#[shorthand(
+    constructor,
+    verify(fn = "Self::count", try_fn = "Self::check", error = "String")
+)]
+struct Verified {
+    value: usize,
+}
+
+static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+impl Verified {
+    fn count(&self) { COUNT.fetch_add(1, Ordering::SeqCst); }
+
+    fn check(&self) -> Result<(), String> {
+        if self.value > 10 {
+            Err(format!("{} is too large", self.value))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[test]
+fn test_constructor() {
+    let example = Example::new("name", 1, Some("other"));
+
+    assert_eq!(
+        example,
+        Example {
+            name: "name".to_string(),
+            value: Some(1),
+            other: Some("other".to_string()),
+            cache: vec![],
+            answer: 42,
+            skipped: false,
+        }
+    );
+}
+
+#[test]
+fn test_constructor_selection() {
+    assert_eq!(
+        Selected::new("name".to_string(), "secret".to_string(), 1),
+        Selected {
+            name: "name".to_string(),
+            secret: "secret".to_string(),
+            _private: 1,
+        }
+    );
+}
+
+#[test]
+fn test_const_constructor() {
+    assert_eq!(
+        ORIGIN,
+        Point {
+            x: 0,
+            y: 0,
+            z: 0,
+            unit: PhantomData
+        }
+    );
+    assert_eq!(Point::new(1, 2).z(), 0);
+}
+
+#[test]
+fn test_constructor_verify() {
+    assert_eq!(Verified::new(1).unwrap().value(), 1);
+    assert_eq!(
+        Verified::new(11).unwrap_err(),
+        "11 is too large".to_string()
+    );
+    assert_eq!(COUNT.load(Ordering::SeqCst), 2);
+}
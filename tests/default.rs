@@ -0,0 +1,94 @@
+#![allow(dead_code)]
+use std::collections::HashMap;
+
+use shorthand::ShortHand;
+
+#[derive(ShortHand, Debug, PartialEq)]
+#[shorthand(default_impl, verify(fn = "Self::verify"))]
+struct Example {
+    #[shorthand(default = "8080")]
+    port: u16,
+    #[shorthand(default = r#""localhost".to_string()"#)]
+    host: String,
+    #[shorthand(default)]
+    headers: HashMap<String, String>,
+    name: String,
+    #[shorthand(default = "true", enable(skip))]
+    skipped: bool,
+    #[shorthand(default = "1", disable(set))]
+    readonly: usize,
+}
+
+impl Example {
+    fn verify(&self) {
+        assert!(self.port != 0);
+    }
+}
+
+#[derive(ShortHand)]
+#[shorthand(rename(reset = "clear_{}"))]
+struct Renamed {
+    #[shorthand(default)]
+    values: Vec<u8>,
+}
+
+#[test]
+fn test_default_impl() {
+    assert_eq!(
+        Example::default(),
+        Example {
+            port: 8080,
+            host: "localhost".to_string(),
+            headers: HashMap::new(),
+            name: String::new(),
+            skipped: true,
+            readonly: 1,
+        }
+    );
+}
+
+#[test]
+fn test_reset_field() {
+    let mut example = Example::default();
+
+    example
+        .set_port(1)
+        .set_host("example.com".to_string())
+        .set_name("name".to_string());
+    example.headers.insert("key".into(), "value".into());
+
+    example.reset_port().reset_headers();
+
+    assert_eq!(example.port(), 8080);
+    assert_eq!(example.host(), &"example.com".to_string());
+    assert!(example.headers().is_empty());
+}
+
+#[test]
+fn test_reset() {
+    let mut example = Example::default();
+
+    example
+        .set_port(1)
+        .set_host("example.com".to_string())
+        .set_name("name".to_string());
+    example.skipped = false;
+    example.readonly = 2;
+
+    example.reset();
+
+    assert_eq!(example.port(), 8080);
+    assert_eq!(example.host(), &"localhost".to_string());
+    assert_eq!(example.name(), &"name".to_string());
+    // only fields with a `reset_<field>` function are reset
+    assert!(!example.skipped);
+    assert_eq!(example.readonly(), 2);
+}
+
+#[test]
+fn test_reset_rename() {
+    let mut renamed = Renamed { values: vec![1] };
+    renamed.clear_values();
+
+    assert!(renamed.values().is_empty());
+}
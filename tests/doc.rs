@@ -0,0 +1,30 @@
+#![deny(missing_docs)]
+//! The derived functions should be documented.
+use shorthand::ShortHand;
+
+/// The struct is public, so all derived functions have to be documented.
+#[derive(ShortHand, Default)]
+#[shorthand(enable(get_mut, must_use, try_into))]
+#[shorthand(doc(get = "Returns the `{type}` of `{field}`."))]
+pub struct Example {
+    /// The name of the example.
+    #[shorthand(doc(set = "Renames the example."))]
+    name: String,
+    value: Option<usize>,
+    #[shorthand(enable(collection_magic))]
+    items: Vec<u8>,
+}
+
+#[test]
+fn test_doc() {
+    let mut example = Example::default();
+
+    example
+        .set_name("name".to_string())
+        .set_value(Some(1))
+        .push_items(2);
+
+    assert_eq!(example.name(), &"name".to_string());
+    assert_eq!(example.value(), Some(1));
+    assert_eq!(example.items(), &vec![2]);
+}
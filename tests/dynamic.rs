@@ -0,0 +1,135 @@
+#![allow(dead_code)]
+use std::cell::Cell;
+
+use shorthand::ShortHand;
+use shorthand_runtime::SetError;
+
+#[derive(ShortHand, Default)]
+#[shorthand(dynamic, verify(fn = "Self::verify"))]
+struct Example {
+    #[shorthand(enable(into))]
+    name: String,
+    #[shorthand(enable(strip_option))]
+    port: Option<u16>,
+    #[shorthand(disable(set))]
+    readonly: usize,
+    #[shorthand(enable(skip))]
+    verified: Cell<usize>,
+}
+
+impl Example {
+    fn verify(&self) { self.verified.set(self.verified.get() + 1); }
+}
+
+#[derive(ShortHand, Default)]
+#[shorthand(dynamic, verify(try_fn = "Self::verify", error = "&'static str"))]
+struct Verified {
+    value: usize,
+    #[shorthand(enable(strip_option))]
+    optional: Option<usize>,
+}
+
+impl Verified {
+    fn verify(&self) -> Result<(), &'static str> {
+        if self.value > 10 {
+            Err("the value is too large")
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[derive(ShortHand, Default)]
+#[shorthand(dynamic)]
+struct Generic<T> {
+    value: T,
+}
+
+#[test]
+fn test_get_dyn() {
+    let example = Example {
+        name: "example".to_string(),
+        readonly: 3,
+        ..Example::default()
+    };
+
+    assert_eq!(
+        example.get_dyn("name").unwrap().downcast_ref::<String>(),
+        Some(&"example".to_string())
+    );
+    assert_eq!(
+        example.get_dyn("readonly").unwrap().downcast_ref::<usize>(),
+        Some(&3)
+    );
+    assert!(example.get_dyn("verified").is_none());
+    assert!(example.get_dyn("unknown").is_none());
+}
+
+#[test]
+fn test_set_dyn() {
+    let mut example = Example::default();
+
+    example
+        .set_dyn("name", Box::new("example".to_string()))
+        .unwrap();
+    assert_eq!(example.name(), &"example".to_string());
+    assert_eq!(example.verified.get(), 1);
+
+    example.set_dyn("port", Box::new(Some(80_u16))).unwrap();
+    assert_eq!(example.port(), Some(80));
+    assert_eq!(example.verified.get(), 2);
+
+    example.set_dyn("port", Box::new(None::<u16>)).unwrap();
+    assert_eq!(example.port(), None);
+    assert_eq!(example.verified.get(), 3);
+
+    match example.set_dyn("name", Box::new(1_usize)) {
+        Err(SetError::WrongType(value)) => assert_eq!(value.downcast_ref::<usize>(), Some(&1)),
+        other => panic!("unexpected result {:?}", other),
+    }
+
+    assert!(matches!(
+        example.set_dyn("readonly", Box::new(1_usize)),
+        Err(SetError::ReadOnly)
+    ));
+    assert!(matches!(
+        example.set_dyn("verified", Box::new(1_usize)),
+        Err(SetError::UnknownField)
+    ));
+    assert_eq!(example.verified.get(), 3);
+}
+
+#[test]
+fn test_set_dyn_verify() {
+    let mut verified = Verified::default();
+
+    verified.set_dyn("value", Box::new(1_usize)).unwrap();
+    verified
+        .set_dyn("optional", Box::new(None::<usize>))
+        .unwrap();
+    assert_eq!(verified.value(), 1);
+
+    match verified.set_dyn("value", Box::new(11_usize)) {
+        Err(SetError::Invalid(error)) => {
+            assert_eq!(
+                error.downcast_ref::<&'static str>(),
+                Some(&"the value is too large")
+            );
+        }
+        other => panic!("unexpected result {:?}", other),
+    }
+
+    // the previous value is restored
+    assert_eq!(verified.value(), 1);
+}
+
+#[test]
+fn test_generic() {
+    let mut generic = Generic::<u8>::default();
+
+    generic.set_dyn("value", Box::new(2_u8)).unwrap();
+    assert_eq!(
+        generic.get_dyn("value").unwrap().downcast_ref::<u8>(),
+        Some(&2)
+    );
+}
@@ -0,0 +1,134 @@
+#![allow(dead_code)]
+use shorthand::ShortHand;
+
+#[derive(ShortHand, Debug, Default, PartialEq)]
+#[shorthand(events(record = "Self::record", derive(Debug, PartialEq)))]
+struct Example {
+    #[shorthand(enable(into))]
+    name: String,
+    #[shorthand(rename(event = "Set{:pascal}"))]
+    port_number: u16,
+    #[shorthand(disable(set))]
+    readonly: usize,
+    #[shorthand(enable(skip))]
+    history: Vec<ExampleEvent>,
+}
+
+impl Example {
+    fn record(&mut self, inverse: ExampleEvent) { self.history.push(inverse); }
+
+    fn undo(&mut self) {
+        if let Some(event) = self.history.pop() {
+            event.apply(self);
+        }
+    }
+}
+
+#[derive(ShortHand, Default)]
+#[shorthand(patch, events(record = "Self::record", derive(Debug, PartialEq)))]
+struct Recorded {
+    #[shorthand(default = "80", enable(try_into))]
+    port: u16,
+    #[shorthand(enable(strip_option))]
+    host: Option<String>,
+    #[shorthand(enable(skip))]
+    history: Vec<RecordedEvent>,
+}
+
+impl Recorded {
+    fn record(&mut self, inverse: RecordedEvent) { self.history.push(inverse); }
+}
+
+#[derive(Debug, PartialEq)]
+struct NotClone(usize);
+
+#[derive(ShortHand)]
+#[shorthand(events)]
+struct Generic<T> {
+    value: NotClone,
+    #[shorthand(enable(skip))]
+    skipped: T,
+}
+
+#[test]
+fn test_apply() {
+    let mut example = Example::default();
+
+    let inverse = ExampleEvent::Name("name".to_string()).apply(&mut example);
+    assert_eq!(example.name(), &"name".to_string());
+    assert_eq!(inverse, ExampleEvent::Name(String::new()));
+
+    let redo = inverse.apply(&mut example);
+    assert_eq!(example.name(), &String::new());
+    assert_eq!(redo, ExampleEvent::Name("name".to_string()));
+
+    // applying an event does not record it
+    assert!(example.history.is_empty());
+}
+
+#[test]
+fn test_record() {
+    let mut example = Example::default();
+
+    example
+        .set_name("first")
+        .set_port_number(80)
+        .set_name("second");
+
+    assert_eq!(
+        example.history,
+        vec![
+            ExampleEvent::Name(String::new()),
+            ExampleEvent::SetPortNumber(0),
+            ExampleEvent::Name("first".to_string()),
+        ]
+    );
+
+    example.undo();
+    assert_eq!(example.name(), &"first".to_string());
+
+    example.undo();
+    example.undo();
+    assert_eq!(example.name(), &String::new());
+    assert_eq!(example.port_number(), 0);
+}
+
+#[test]
+fn test_generic() {
+    let mut generic = Generic {
+        value: NotClone(1),
+        skipped: (),
+    };
+
+    let inverse = GenericEvent::Value(NotClone(2)).apply(&mut generic);
+
+    assert_eq!(generic.value(), &NotClone(2));
+    assert!(matches!(inverse, GenericEvent::Value(NotClone(1))));
+}
+
+#[test]
+fn test_record_other_functions() {
+    let mut recorded = Recorded::default();
+
+    recorded.try_port(8080_u32).unwrap();
+    recorded.reset_port();
+    recorded.set_host("localhost".to_string()).set_port(1);
+    recorded.reset();
+    // the setter can not set `None`, so the patch assigns it directly
+    recorded.apply_patch(RecordedPatch {
+        host: Some(None),
+        ..RecordedPatch::default()
+    });
+
+    assert_eq!(
+        recorded.history,
+        vec![
+            RecordedEvent::Port(0),
+            RecordedEvent::Port(8080),
+            RecordedEvent::Host(None),
+            RecordedEvent::Port(80),
+            RecordedEvent::Port(1),
+            RecordedEvent::Host(Some("localhost".to_string())),
+        ]
+    );
+}
@@ -0,0 +1,49 @@
+#![allow(dead_code)]
+use shorthand::ShortHand;
+use std::marker::PhantomData;
+
+#[derive(ShortHand)]
+#[shorthand(field_info)]
+struct Example<'a, T> {
+    #[shorthand(enable(into))]
+    name: String,
+    #[shorthand(disable(set), enable(get_mut))]
+    values: Vec<T>,
+    #[shorthand(disable(set))]
+    port: u16,
+    #[shorthand(enable(copy))]
+    reference: &'a str,
+    #[shorthand(enable(skip))]
+    skipped: usize,
+    marker: PhantomData<T>,
+}
+
+#[test]
+fn test_field_info() {
+    let fields = Example::<'static, usize>::FIELDS;
+
+    assert_eq!(
+        fields.iter().map(|info| info.name).collect::<Vec<_>>(),
+        vec!["name", "values", "port", "reference", "skipped", "marker"]
+    );
+
+    assert_eq!(fields[0].type_name, "String");
+    assert_eq!(fields[0].accessors, &["name", "set_name"]);
+    assert!(!fields[0].read_only && !fields[0].copy && !fields[0].skipped);
+
+    assert_eq!(fields[1].type_name, "Vec<T>");
+    assert_eq!(fields[1].accessors, &["values", "values_mut"]);
+    assert!(!fields[1].read_only);
+
+    assert_eq!(fields[2].type_name, "u16");
+    assert!(fields[2].read_only && fields[2].copy);
+
+    assert_eq!(fields[3].type_name, "&'a str");
+    assert!(fields[3].copy);
+
+    assert!(fields[4].skipped && fields[4].read_only && !fields[4].copy);
+    assert!(fields[4].accessors.is_empty());
+
+    assert!(fields[5].skipped);
+    assert!(fields[0].has_accessor("set_name") && !fields[2].has_accessor("set_port"));
+}
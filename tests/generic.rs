@@ -0,0 +1,47 @@
+#![allow(dead_code)]
+use shorthand::ShortHand;
+
+#[derive(ShortHand, Default)]
+#[shorthand(enable(into, try_into))]
+struct Value<VALUE, VALUE_, MY_VALUE_T> {
+    value: VALUE,
+    other: VALUE_,
+    data: MY_VALUE_T,
+    name: String,
+}
+
+#[derive(ShortHand, Default)]
+#[shorthand(enable(into, try_into), generic = "V")]
+struct Named {
+    value: u8,
+    #[shorthand(generic = "T", enable(strip_option))]
+    option: Option<String>,
+}
+
+#[test]
+fn test_value_generic() {
+    let mut value = Value::<usize, u8, String>::default();
+
+    value
+        .set_value(1_usize)
+        .set_other(2_u8)
+        .set_data("data")
+        .set_name("name");
+    value.try_value(3_u8).unwrap();
+
+    assert_eq!(value.value(), &3);
+    assert_eq!(value.other(), &2);
+    assert_eq!(value.data(), &"data".to_string());
+    assert_eq!(value.name(), &"name".to_string());
+}
+
+#[test]
+fn test_named_generic() {
+    let mut named = Named::default();
+
+    named.set_value::<u8>(1).set_option::<&str>("option");
+
+    assert_eq!(named.value(), 1);
+    assert_eq!(named.option(), Some(&"option".to_string()));
+    assert!(named.try_value::<u16>(256).is_err());
+}
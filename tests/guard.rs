@@ -0,0 +1,93 @@
+use shorthand::ShortHand;
+
+#[derive(ShortHand, Default)]
+#[shorthand(
+    verify(fn = "Self::verify", try_fn = "Self::try_verify", error = "String"),
+    enable(get_mut, guard)
+)]
+struct Example {
+    value: usize,
+    #[shorthand(disable(guard))]
+    other: Vec<usize>,
+}
+
+impl Example {
+    fn verify(&self) {
+        if self.value > 10 {
+            panic!("value must not be greater than 10");
+        }
+    }
+
+    fn try_verify(&self) -> Result<(), String> {
+        if self.value > 10 {
+            Err("value must not be greater than 10".to_string())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[test]
+fn test_guard() {
+    let mut example = Example::default();
+
+    *example.value_mut() += 5;
+    assert_eq!(example.value(), 5);
+
+    let _: &mut Vec<usize> = example.other_mut();
+}
+
+#[test]
+#[should_panic = "value must not be greater than 10"]
+fn test_guard_verify_on_drop() {
+    let mut example = Example::default();
+
+    *example.value_mut() = 11;
+}
+
+#[test]
+fn test_try_guard_commit() {
+    let mut example = Example::default();
+
+    let mut guard = example.try_value_mut();
+    *guard = 10;
+    assert_eq!(guard.commit(), Ok(()));
+
+    assert_eq!(example.value(), 10);
+}
+
+#[test]
+fn test_try_guard_rollback() {
+    let mut example = Example::default();
+
+    let mut guard = example.try_value_mut();
+    *guard = 11;
+    assert_eq!(
+        guard.commit(),
+        Err("value must not be greater than 10".to_string())
+    );
+
+    assert_eq!(example.value(), 0);
+
+    // dropping the guard without committing restores the previous value
+    *example.try_value_mut() = 3;
+    assert_eq!(example.value(), 0);
+}
+
+#[derive(ShortHand, Default)]
+#[shorthand(enable(get_mut, guard))]
+struct Generic<'a, T> {
+    value: Option<T>,
+    reference: &'a str,
+}
+
+#[test]
+fn test_guard_generics() {
+    let mut example: Generic<'_, usize> = Generic::default();
+
+    *example.value_mut() = Some(1);
+    *example.reference_mut() = "hello";
+
+    assert_eq!(example.value(), Some(&1));
+    assert_eq!(example.reference(), "hello");
+}
@@ -0,0 +1,34 @@
+#![allow(dead_code)]
+use shorthand::ShortHand;
+
+#[derive(ShortHand, Default)]
+#[shorthand(inline(hint))]
+struct Example {
+    hint: usize,
+    #[shorthand(inline(always))]
+    always: usize,
+    #[shorthand(inline(never))]
+    never: usize,
+    #[shorthand(disable(inline))]
+    disabled: usize,
+    #[shorthand(disable(inline), inline(hint))]
+    enabled: usize,
+}
+
+#[test]
+fn test_inline() {
+    let mut example = Example::default();
+
+    example
+        .set_hint(1)
+        .set_always(2)
+        .set_never(3)
+        .set_disabled(4)
+        .set_enabled(5);
+
+    assert_eq!(example.hint(), 1);
+    assert_eq!(example.always(), 2);
+    assert_eq!(example.never(), 3);
+    assert_eq!(example.disabled(), 4);
+    assert_eq!(example.enabled(), 5);
+}
@@ -0,0 +1,153 @@
+#![allow(dead_code)]
+use std::cell::Cell;
+
+use shorthand::ShortHand;
+use shorthand_runtime::FieldParseError;
+
+#[derive(ShortHand, Default)]
+#[shorthand(enable(parse), verify(fn = "Self::verify"))]
+struct Example {
+    #[shorthand(enable(into))]
+    name: String,
+    port: u16,
+    timeout: Option<u64>,
+    #[shorthand(enable(strip_option))]
+    retries: Option<u8>,
+    #[shorthand(disable(set))]
+    readonly: usize,
+    #[shorthand(disable(parse))]
+    unparsed: usize,
+    #[shorthand(enable(skip))]
+    verified: Cell<usize>,
+}
+
+impl Example {
+    fn verify(&self) { self.verified.set(self.verified.get() + 1); }
+}
+
+#[derive(ShortHand, Default)]
+#[shorthand(rename(parse = "parse_{}"))]
+struct Renamed {
+    #[shorthand(enable(parse))]
+    value: i32,
+}
+
+#[derive(ShortHand, Default)]
+#[shorthand(enable(parse), verify(try_fn = "Self::verify", error = "&'static str"))]
+struct Verified {
+    port: u16,
+    #[shorthand(enable(strip_option))]
+    timeout: Option<u64>,
+}
+
+impl Verified {
+    fn verify(&self) -> Result<(), &'static str> {
+        if self.port == 0 && self.timeout.is_none() {
+            Err("either port or timeout must be set")
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[test]
+fn test_set_from_str() {
+    let mut example = Example::default();
+
+    example
+        .set_name_from_str("example")
+        .unwrap()
+        .set_port_from_str("8080")
+        .unwrap();
+
+    assert_eq!(example.name(), &"example".to_string());
+    assert_eq!(example.port(), 8080);
+    assert_eq!(example.verified.get(), 2);
+
+    example.set_timeout_from_str("30").unwrap();
+    example.set_retries_from_str("3").unwrap();
+
+    assert_eq!(example.timeout(), Some(30));
+    assert_eq!(example.retries(), Some(3));
+    assert_eq!(example.verified.get(), 4);
+}
+
+#[test]
+fn test_set_from_str_error() {
+    let mut example = Example::default();
+    let error = example.set_port_from_str("port").err().unwrap();
+
+    assert_eq!(error.field(), "port");
+    assert_eq!(
+        error,
+        FieldParseError::Invalid {
+            field: "port",
+            message: "invalid digit found in string".to_string(),
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "invalid value for field `port`: invalid digit found in string"
+    );
+    assert_eq!(example.port(), 0);
+    assert_eq!(example.verified.get(), 0);
+}
+
+#[test]
+fn test_set_by_name_from_str() {
+    let mut example = Example::default();
+
+    example.set_by_name_from_str("port", "80").unwrap();
+    example.set_by_name_from_str("timeout", "5").unwrap();
+
+    assert_eq!(example.port(), 80);
+    assert_eq!(example.timeout(), Some(5));
+
+    assert_eq!(
+        example.set_by_name_from_str("readonly", "1").err().unwrap(),
+        FieldParseError::UnknownField("readonly".to_string())
+    );
+    assert_eq!(
+        example
+            .set_by_name_from_str("unparsed", "1")
+            .err()
+            .unwrap()
+            .field(),
+        "unparsed"
+    );
+    assert!(example.set_by_name_from_str("verified", "1").is_err());
+}
+
+#[test]
+fn test_set_from_str_verify_error() {
+    let mut verified = Verified::default();
+    let error = FieldParseError::Invalid {
+        field: "port",
+        message: "either port or timeout must be set".to_string(),
+    };
+
+    assert_eq!(verified.set_port_from_str("0").err(), Some(error.clone()));
+    assert_eq!(
+        verified.set_by_name_from_str("port", "0").err(),
+        Some(error)
+    );
+
+    verified.set_port_from_str("80").unwrap();
+    assert!(verified.set_port_from_str("0").is_err());
+    assert_eq!(verified.port(), 80);
+
+    verified.set_timeout_from_str("5").unwrap();
+    verified.set_port_from_str("0").unwrap();
+    assert_eq!(verified.port(), 0);
+}
+
+#[test]
+fn test_renamed() {
+    let mut renamed = Renamed::default();
+
+    renamed.parse_value("-1").unwrap();
+    assert_eq!(renamed.value(), -1);
+
+    renamed.set_by_name_from_str("value", "2").unwrap();
+    assert_eq!(renamed.value(), 2);
+}
@@ -0,0 +1,96 @@
+#![allow(dead_code)]
+use std::cell::Cell;
+
+use shorthand::ShortHand;
+
+#[derive(ShortHand, Debug, Clone, PartialEq)]
+#[shorthand(parts)]
+struct Example<T> {
+    #[shorthand(enable(into_field))]
+    name: String,
+    #[shorthand(enable(into_field), rename(into_field = "take_{}"))]
+    value: T,
+    #[shorthand(enable(skip))]
+    secret: Vec<u8>,
+    #[cfg(test)]
+    tested: bool,
+}
+
+fn example() -> Example<usize> {
+    Example {
+        name: "name".to_string(),
+        value: 1,
+        secret: vec![1, 2],
+        tested: true,
+    }
+}
+
+#[test]
+fn test_into_field() {
+    assert_eq!(example().into_name(), "name".to_string());
+    assert_eq!(example().take_value(), 1);
+}
+
+#[test]
+fn test_into_parts() {
+    let ExampleParts {
+        name,
+        value,
+        secret,
+        tested,
+    } = example().into_parts();
+
+    assert_eq!(name, "name".to_string());
+    assert_eq!(value, 1);
+    assert_eq!(secret, vec![1, 2]);
+    assert!(tested);
+}
+
+#[test]
+fn test_from_parts() {
+    assert_eq!(Example::from_parts(example().into_parts()), example());
+}
+
+#[derive(ShortHand, Debug)]
+#[shorthand(
+    parts,
+    verify(fn = "Self::count", try_fn = "Self::check", error = "String")
+)]
+struct Verified {
+    value: usize,
+    #[shorthand(enable(skip))]
+    calls: Cell<usize>,
+}
+
+impl Verified {
+    fn count(&self) { self.calls.set(self.calls.get() + 1); }
+
+    fn check(&self) -> Result<(), String> {
+        if self.value > 10 {
+            Err(format!("{} is too large", self.value))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[test]
+fn test_from_parts_verify() {
+    let verified = Verified::from_parts(VerifiedParts {
+        value: 1,
+        calls: Cell::new(0),
+    })
+    .unwrap();
+
+    assert_eq!(verified.value(), 1);
+    assert_eq!(verified.calls.get(), 1);
+
+    assert_eq!(
+        Verified::from_parts(VerifiedParts {
+            value: 11,
+            calls: Cell::new(0),
+        })
+        .unwrap_err(),
+        "11 is too large".to_string()
+    );
+}
@@ -0,0 +1,120 @@
+#![allow(dead_code)]
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use shorthand::ShortHand;
+
+static VERIFIED: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(ShortHand, Debug, Clone, PartialEq, Default)]
+#[shorthand(patch(diff), verify(fn = "Self::verify"))]
+struct Example {
+    #[shorthand(enable(into))]
+    name: String,
+    #[shorthand(enable(strip_option))]
+    port: Option<u16>,
+    #[shorthand(rename(set = "change_{}"))]
+    values: Vec<u8>,
+    #[shorthand(disable(set))]
+    readonly: usize,
+    #[shorthand(enable(skip))]
+    skipped: bool,
+}
+
+impl Example {
+    fn verify(&self) { VERIFIED.fetch_add(1, Ordering::SeqCst); }
+}
+
+// only `U` has to implement `PartialEq` and `Clone` for `diff`, because the
+// field with `T` is not part of the patch
+#[derive(ShortHand, Default)]
+#[shorthand(patch(diff))]
+struct Generic<T, U> {
+    #[shorthand(enable(skip))]
+    value: T,
+    other: usize,
+    generic: U,
+}
+
+/// Does not implement `PartialEq`, so `diff` can not be derived.
+#[derive(Default)]
+struct NoEq;
+
+#[derive(ShortHand, Default)]
+#[shorthand(patch)]
+struct Opaque {
+    value: NoEq,
+}
+
+#[test]
+fn test_apply_patch() {
+    let mut example = Example {
+        port: Some(80),
+        ..Example::default()
+    };
+
+    example.apply_patch(ExamplePatch {
+        name: Some("name".to_string()),
+        values: Some(vec![1]),
+        ..ExamplePatch::default()
+    });
+
+    assert_eq!(example.name(), &"name".to_string());
+    assert_eq!(example.port(), Some(80));
+    assert_eq!(example.values(), &vec![1]);
+
+    let verified = VERIFIED.load(Ordering::SeqCst);
+
+    example.apply_patch(ExamplePatch {
+        port: Some(None),
+        ..ExamplePatch::default()
+    });
+
+    assert_eq!(example.port(), None);
+    assert!(VERIFIED.load(Ordering::SeqCst) > verified);
+}
+
+#[test]
+fn test_diff() {
+    let example = Example::default();
+    let mut other = example.clone();
+    other.set_name("other").set_port(8080).readonly = 1;
+
+    let patch = example.diff(&other);
+
+    assert_eq!(patch.name, Some("other".to_string()));
+    assert_eq!(patch.port, Some(Some(8080)));
+    assert_eq!(patch.values, None);
+    assert!(example.diff(&example).is_empty());
+
+    let mut patched = example.clone();
+    patched.apply_patch(patch);
+
+    assert_eq!(patched.name(), other.name());
+    assert_eq!(patched.port(), other.port());
+}
+
+#[test]
+fn test_generic_patch() {
+    let mut generic = Generic::<String, NoEq>::default();
+
+    generic.apply_patch(GenericPatch {
+        other: Some(1),
+        generic: Some(NoEq),
+        ..GenericPatch::default()
+    });
+
+    assert_eq!(generic.other(), 1);
+
+    let generic = Generic::<NoEq, usize>::default();
+    let mut other = Generic::<NoEq, usize>::default();
+    other.set_generic(2);
+
+    assert_eq!(generic.diff(&other).generic, Some(2));
+}
+
+#[test]
+fn test_patch_without_diff() {
+    let mut opaque = Opaque::default();
+
+    opaque.apply_patch(OpaquePatch { value: Some(NoEq) });
+}
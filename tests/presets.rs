@@ -0,0 +1,43 @@
+#![allow(dead_code)]
+use shorthand::ShortHand;
+
+#[derive(ShortHand, Default)]
+#[shorthand(readonly)]
+struct Example {
+    id: usize,
+    #[shorthand(writeonly)]
+    secret: String,
+    #[shorthand(enable(get_mut))]
+    data: Vec<u8>,
+    #[shorthand(opaque, enable(set))]
+    hidden: bool,
+    #[shorthand(enable(set))]
+    name: String,
+}
+
+#[test]
+fn test_readonly() {
+    let example = Example::default();
+
+    assert_eq!(example.id(), 0);
+}
+
+#[test]
+fn test_writeonly() {
+    let mut example = Example::default();
+
+    example.set_secret("hunter2".to_string());
+    assert_eq!(example.secret, "hunter2".to_string());
+}
+
+#[test]
+fn test_preset_with_enable() {
+    let mut example = Example::default();
+
+    example.data_mut().push(1);
+    example.set_hidden(true).set_name("name".to_string());
+
+    assert_eq!(example.data(), &vec![1]);
+    assert_eq!(example.hidden, true);
+    assert_eq!(example.name(), &"name".to_string());
+}
@@ -6,6 +6,7 @@ fn tests() {
 
     // verify attribute
     t.pass("tests/verify/simple_verify.rs");
+    t.pass("tests/verify/try_verify.rs");
     t.compile_fail("tests/verify/unexpected_lit.rs");
     t.compile_fail("tests/verify/expected_fn.rs");
     t.compile_fail("tests/verify/unexpected_meta.rs");
@@ -41,10 +42,28 @@ fn tests() {
     // attribute errors:
     t.compile_fail("tests/ui/not_copy.rs");
     t.compile_fail("tests/ui/unknown_visibility.rs");
+    t.compile_fail("tests/ui/accessor_visibility.rs");
     t.compile_fail("tests/ui/redundant_disable_enable.rs");
     t.compile_fail("tests/ui/unknown_field.rs");
     t.compile_fail("tests/ui/unexpected_lit.rs");
     t.compile_fail("tests/ui/duplicate_enable_enable.rs");
+    t.compile_fail("tests/ui/redundant_preset.rs");
+    t.compile_fail("tests/ui/unknown_profile.rs");
+    t.compile_fail("tests/ui/inline_level.rs");
+    t.compile_fail("tests/ui/doc_template.rs");
+    t.compile_fail("tests/ui/accessor_attributes.rs");
+    t.compile_fail("tests/ui/attrs.rs");
+    t.compile_fail("tests/ui/selection.rs");
+    t.compile_fail("tests/ui/strip.rs");
+    t.compile_fail("tests/ui/rename_case.rs");
+    t.compile_fail("tests/ui/bool_prefix.rs");
+    t.compile_fail("tests/ui/collision.rs");
+    t.compile_fail("tests/ui/constructor.rs");
+    t.compile_fail("tests/ui/constructor_skip.rs");
+    t.compile_fail("tests/ui/patch_diff.rs");
+    t.compile_fail("tests/ui/default.rs");
+    t.compile_fail("tests/ui/events.rs");
+    t.compile_fail("tests/ui/parse.rs");
 
     // rename
     t.compile_fail("tests/ui/rename/rename_path.rs");
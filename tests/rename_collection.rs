@@ -0,0 +1,36 @@
+#![allow(dead_code)]
+use shorthand::ShortHand;
+use std::collections::{BTreeSet, HashMap};
+
+#[derive(ShortHand, Default)]
+#[shorthand(enable(collection_magic, get_mut))]
+#[shorthand(rename(format = "my_{}"))]
+struct Example {
+    values: Vec<usize>,
+    #[shorthand(rename(push = "add_{}", get = "{}"))]
+    items: Vec<usize>,
+    #[shorthand(rename(insert = "put_{}", get = "{}"))]
+    map: HashMap<usize, usize>,
+    set: BTreeSet<usize>,
+    #[shorthand(disable(rename))]
+    plain: Vec<usize>,
+}
+
+#[test]
+fn test_rename_collection() {
+    let mut example = Example::default();
+
+    example
+        .push_my_values(1)
+        .add_items(2)
+        .put_map(3, 4)
+        .insert_my_set(5)
+        .push_plain(6);
+    example.my_values_mut().push(7);
+
+    assert_eq!(example.my_values(), &vec![1, 7]);
+    assert_eq!(example.items(), &vec![2]);
+    assert_eq!(example.map().get(&3), Some(&4));
+    assert!(example.my_set().contains(&5));
+    assert_eq!(example.plain(), &vec![6]);
+}
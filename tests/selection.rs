@@ -0,0 +1,47 @@
+#![allow(dead_code)]
+use shorthand::ShortHand;
+
+#[derive(ShortHand, Default)]
+#[shorthand(only(value, name, cache_name))]
+#[shorthand(exclude_prefix = "cache_")]
+struct Example {
+    value: usize,
+    name: String,
+    cache_name: Option<String>,
+    other: bool,
+}
+
+#[derive(ShortHand, Default)]
+#[shorthand(except(secret))]
+struct Except {
+    value: usize,
+    secret: String,
+}
+
+// these would conflict with the functions, if they were derived
+impl Example {
+    fn other(&self) -> usize { 0 }
+
+    fn cache_name(&self) -> usize { 0 }
+}
+
+impl Except {
+    fn secret(&self) -> usize { 0 }
+}
+
+#[test]
+fn test_selection() {
+    let mut example = Example::default();
+    example.set_value(1).set_name("name".to_string());
+
+    assert_eq!(example.value(), 1);
+    assert_eq!(example.name(), &"name".to_string());
+    assert_eq!(example.other(), 0);
+    assert_eq!(example.cache_name(), 0);
+
+    let mut except = Except::default();
+    except.set_value(2);
+
+    assert_eq!(except.value(), 2);
+    assert_eq!(except.secret(), 0);
+}
@@ -0,0 +1,74 @@
+#![allow(dead_code)]
+use shorthand::ShortHand;
+
+#[derive(ShortHand, Default)]
+#[shorthand(strip_prefix = "m_", enable(get_mut, collection_magic, try_into))]
+struct Example {
+    m_count: usize,
+    #[shorthand(strip_prefix = "_", strip_suffix = "_")]
+    _name_: String,
+    m_values: Vec<usize>,
+    #[shorthand(strip_prefix = "")]
+    m_other: usize,
+}
+
+// the stripped name `type` is a keyword, but the functions have other names
+#[derive(ShortHand, Default)]
+#[shorthand(strip_prefix = "m_", rename("get_{}"))]
+struct Keyword {
+    m_type: usize,
+    #[shorthand(disable(get, rename))]
+    m_match: usize,
+}
+
+#[derive(ShortHand, Default)]
+#[shorthand(enable(ignore_underscore))]
+struct Underscore {
+    _private: usize,
+    public: _Type,
+}
+
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+#[allow(non_camel_case_types)]
+struct _Type;
+
+impl Underscore {
+    // this would conflict with the getter, if it was derived
+    fn _private(&self) -> &'static str { "private" }
+}
+
+#[test]
+fn test_strip() {
+    let mut example = Example::default();
+
+    example
+        .set_count(1)
+        .set_name("name".to_string())
+        .push_values(2)
+        .set_m_other(3);
+    *example.count_mut() += 1;
+    example.try_count(4_u8).unwrap();
+
+    assert_eq!(example.count(), 4);
+    assert_eq!(example.name(), &"name".to_string());
+    assert_eq!(example.values(), &vec![2]);
+    assert_eq!(example.m_other(), 3);
+}
+
+#[test]
+fn test_ignore_underscore() {
+    let underscore = Underscore::default();
+
+    assert_eq!(underscore._private(), "private");
+    assert_eq!(underscore.public(), &_Type);
+}
+
+#[test]
+fn test_strip_keyword() {
+    let mut keyword = Keyword::default();
+
+    keyword.set_get_type(1).set_match(2);
+
+    assert_eq!(keyword.get_type(), 1);
+    assert_eq!(keyword.m_match, 2);
+}
@@ -0,0 +1,15 @@
+#![deny(unused_must_use)]
+use shorthand::ShortHand;
+
+#[derive(ShortHand, Default)]
+pub struct Example {
+    #[shorthand_get(must_use)]
+    value: usize,
+}
+
+fn main() {
+    let mut example = Example::default();
+
+    example.value();
+    example.set_value(1);
+}
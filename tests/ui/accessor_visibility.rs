@@ -0,0 +1,16 @@
+mod example {
+    use shorthand::ShortHand;
+
+    #[derive(ShortHand, Default)]
+    #[shorthand(visibility(get = "pub", set = "pub(self)"))]
+    pub struct Example {
+        value: usize,
+    }
+}
+
+fn main() {
+    let mut example = example::Example::default();
+
+    let _: usize = example.value();
+    example.set_value(1);
+}
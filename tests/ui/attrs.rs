@@ -0,0 +1,15 @@
+use shorthand::ShortHand;
+
+#[derive(ShortHand, Default)]
+pub struct Example {
+    #[shorthand(attrs(gett = "#[must_use]"))]
+    unknown: usize,
+    #[shorthand(attrs(get = "must_use"))]
+    invalid: usize,
+    #[shorthand(attrs(set = 1))]
+    literal: usize,
+    #[shorthand(attrs = "#[must_use]")]
+    name_value: usize,
+}
+
+fn main() {}
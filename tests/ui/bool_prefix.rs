@@ -0,0 +1,13 @@
+use shorthand::ShortHand;
+
+#[derive(ShortHand, Default)]
+pub struct Example {
+    #[shorthand(bool_prefix = "is-")]
+    invalid: bool,
+    #[shorthand(bool_prefix("is_"))]
+    list: bool,
+    #[shorthand(bool_prefix = true)]
+    literal: bool,
+}
+
+fn main() {}
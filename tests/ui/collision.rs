@@ -0,0 +1,29 @@
+use shorthand::ShortHand;
+
+#[derive(ShortHand, Default)]
+#[shorthand(reserved(len, new))]
+pub struct Example {
+    value: usize,
+    set_value: usize,
+    #[shorthand(enable(get_mut))]
+    data: Vec<u8>,
+    #[shorthand(disable(set))]
+    data_mut: Vec<u8>,
+    #[shorthand(rename(get = "both", set = "both"))]
+    twice: usize,
+    len: usize,
+}
+
+#[derive(ShortHand, Default)]
+pub struct Field {
+    #[shorthand(reserved(other))]
+    value: usize,
+}
+
+#[derive(ShortHand, Default)]
+#[shorthand(constructor, field_info, reserved(FIELDS))]
+pub struct Derived {
+    new: usize,
+}
+
+fn main() {}
@@ -0,0 +1,59 @@
+use shorthand::ShortHand;
+
+#[derive(ShortHand)]
+#[shorthand(default = "1")]
+struct StructDefault {
+    value: usize,
+}
+
+#[derive(ShortHand)]
+struct FieldConstructor {
+    #[shorthand(constructor)]
+    value: usize,
+}
+
+#[derive(ShortHand)]
+#[shorthand(constructor)]
+struct InvalidDefault {
+    #[shorthand(default(value))]
+    value: usize,
+    #[shorthand(default = 1)]
+    other: usize,
+}
+
+#[derive(ShortHand)]
+#[shorthand(constructor, enable(const_fn))]
+struct ConstInto {
+    #[shorthand(enable(into))]
+    value: String,
+}
+
+#[derive(ShortHand)]
+#[shorthand(constructor, enable(const_fn))]
+struct ConstDefault {
+    #[shorthand(default)]
+    value: usize,
+    #[shorthand(enable(skip))]
+    skipped: usize,
+    #[shorthand(enable(skip), default = "0")]
+    constant: usize,
+    marker: std::marker::PhantomData<u8>,
+}
+
+#[derive(ShortHand)]
+#[shorthand(
+    constructor,
+    enable(const_fn),
+    verify(fn = "Self::verify", try_fn = "Self::check", error = "String")
+)]
+struct ConstVerify {
+    value: usize,
+}
+
+impl ConstVerify {
+    fn verify(&self) {}
+
+    fn check(&self) -> Result<(), String> { Ok(()) }
+}
+
+fn main() {}
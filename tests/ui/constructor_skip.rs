@@ -0,0 +1,15 @@
+use shorthand::ShortHand;
+
+struct NoDefault;
+
+#[derive(ShortHand)]
+#[shorthand(constructor)]
+struct Skipped {
+    value: usize,
+    #[shorthand(enable(skip))]
+    skipped: NoDefault,
+    #[shorthand(enable(skip), default = "NoDefault")]
+    explicit: NoDefault,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use shorthand::ShortHand;
+
+struct NotDefault;
+
+#[derive(ShortHand)]
+struct Example {
+    #[shorthand(default)]
+    value: NotDefault,
+}
+
+fn main() {}
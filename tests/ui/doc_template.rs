@@ -0,0 +1,17 @@
+use shorthand::ShortHand;
+
+#[derive(ShortHand)]
+#[shorthand(doc(get = "Returns `{name}`."))]
+pub struct Placeholder {
+    value: String,
+}
+
+#[derive(ShortHand)]
+pub struct Command {
+    #[shorthand(doc(sett = "Sets `{field}`.", get = "Returns `{field`."))]
+    value: String,
+    #[shorthand(doc = "Sets `{field}`.")]
+    other: String,
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+use shorthand::ShortHand;
+
+#[derive(ShortHand)]
+#[shorthand(events(record = 1, derive("Debug"), log = "Self::log"))]
+struct InvalidOptions {
+    value: usize,
+}
+
+#[derive(ShortHand)]
+#[shorthand(events = "Self::record")]
+struct NameValue {
+    value: usize,
+}
+
+#[derive(ShortHand)]
+struct FieldEvents {
+    #[shorthand(events)]
+    value: usize,
+}
+
+fn main() {}
@@ -1,9 +1,9 @@
 use shorthand::ShortHand;
 
 #[derive(ShortHand)]
-#[shorthand(enable(into))]
-struct DoNotDoThis<VALUE> {
-    value: VALUE,
+#[shorthand(enable(into), generic = "T")]
+struct DoNotDoThis<T> {
+    value: T,
 }
 
 fn main() {}
@@ -2,8 +2,11 @@ use shorthand::ShortHand;
 
 #[derive(ShortHand)]
 #[shorthand(enable(try_into))]
-struct DoNotDoThis<VALUE> {
-    value: VALUE,
+struct DoNotDoThis<const N: usize> {
+    #[shorthand(generic = "N")]
+    value: [u8; N],
+    #[shorthand(generic = "not an ident")]
+    other: usize,
 }
 
 fn main() {}
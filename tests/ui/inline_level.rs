@@ -0,0 +1,16 @@
+use shorthand::ShortHand;
+
+#[derive(ShortHand)]
+#[shorthand(inline(hint))]
+pub struct Command {
+    #[shorthand(inline(hint))]
+    redundant: String,
+    #[shorthand(inline(sometimes))]
+    unknown: String,
+    #[shorthand(inline)]
+    path: String,
+    #[shorthand(enable(inline))]
+    enabled: String,
+}
+
+fn main() {}
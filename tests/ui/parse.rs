@@ -0,0 +1,15 @@
+use shorthand::ShortHand;
+
+#[derive(ShortHand, Default)]
+#[shorthand(enable(parse))]
+pub struct ByName {
+    by_name: usize,
+}
+
+#[derive(ShortHand, Default)]
+#[shorthand(enable(parse), reserved(set_by_name_from_str))]
+pub struct Reserved {
+    value: usize,
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use shorthand::ShortHand;
+
+#[derive(Default)]
+struct NoEq;
+
+#[derive(ShortHand, Default)]
+#[shorthand(patch(diff))]
+struct Opaque {
+    value: NoEq,
+}
+
+#[derive(ShortHand, Default)]
+#[shorthand(patch(apply))]
+struct Unknown {
+    value: usize,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use shorthand::ShortHand;
+
+#[derive(ShortHand)]
+#[shorthand(readonly)]
+pub struct Command {
+    #[shorthand(readonly)]
+    value: String,
+    #[shorthand(writeonly, enable(set))]
+    other: String,
+    #[shorthand(disable(get), opaque)]
+    opaque: String,
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use shorthand::ShortHand;
+
+#[derive(ShortHand, Default)]
+#[shorthand(rename_all = "kebab-case")]
+pub struct Example {
+    value: usize,
+}
+
+#[derive(ShortHand, Default)]
+#[shorthand(rename_all = "camelCase")]
+pub struct Other {
+    #[shorthand(rename(get = "get_{:kebab}"))]
+    value: usize,
+    #[shorthand(rename_all = "camelCase")]
+    other: usize,
+}
+
+fn main() {}
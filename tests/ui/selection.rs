@@ -0,0 +1,17 @@
+use shorthand::ShortHand;
+
+#[derive(ShortHand, Default)]
+#[shorthand(only(value, valeu), except(value, value))]
+pub struct Example {
+    value: usize,
+    #[shorthand(except(other))]
+    other: usize,
+}
+
+#[derive(ShortHand, Default)]
+#[shorthand(exclude_prefix = "", only("value"))]
+pub struct Invalid {
+    value: usize,
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+use shorthand::ShortHand;
+
+#[derive(ShortHand, Default)]
+#[shorthand(strip_prefix = "m_")]
+pub struct Example {
+    m_: usize,
+    m_1: usize,
+    m_type: usize,
+}
+
+#[derive(ShortHand, Default)]
+pub struct Invalid {
+    #[shorthand(strip_suffix("_"))]
+    value_: usize,
+    #[shorthand(strip_suffix = 1)]
+    other_: usize,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use shorthand::ShortHand;
+
+#[derive(ShortHand)]
+#[shorthand(profile = "unknown")]
+pub struct Command {
+    value: String,
+}
+
+#[derive(ShortHand)]
+pub struct Field {
+    #[shorthand(profile = "domain")]
+    value: String,
+}
+
+fn main() {}
@@ -0,0 +1,57 @@
+use shorthand::ShortHand;
+
+#[derive(ShortHand)]
+#[shorthand(verify(try_fn = "Self::verify_field", error = "&'static str"))]
+struct Example {
+    field: usize,
+    #[shorthand(enable(strip_option))]
+    optional: Option<usize>,
+}
+
+impl Example {
+    fn verify_field(&self) -> Result<(), &'static str> {
+        if self.field == 0 {
+            Err("the field should not be zero")
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[test]
+fn test_try_set() {
+    let mut example = Example {
+        field: 1,
+        optional: None,
+    };
+
+    assert_eq!(example.try_set_field(2).map(|e| e.field()), Ok(2));
+    assert_eq!(example.try_set_optional(3).map(|e| e.optional()), Ok(Some(3)));
+}
+
+#[test]
+fn test_try_set_failed() {
+    let mut example = Example {
+        field: 1,
+        optional: None,
+    };
+
+    assert_eq!(
+        example.try_set_field(0).err(),
+        Some("the field should not be zero")
+    );
+    assert_eq!(example.field(), 1);
+}
+
+#[test]
+#[should_panic = "`Self::verify_field` failed to verify the value"]
+fn test_set_failed() {
+    let mut example = Example {
+        field: 1,
+        optional: None,
+    };
+
+    let _: &mut Example = example.set_field(0);
+}
+
+fn main() {}
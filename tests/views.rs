@@ -0,0 +1,96 @@
+#![allow(dead_code)]
+use shorthand::ShortHand;
+
+#[derive(ShortHand, Default)]
+#[shorthand(views, enable(get_mut))]
+struct Example<T> {
+    name: String,
+    values: Vec<T>,
+    #[shorthand(disable(get_mut))]
+    count: usize,
+    #[shorthand(disable(get, get_mut))]
+    write_only: usize,
+    #[shorthand(enable(guard))]
+    guarded: usize,
+    #[shorthand(enable(skip))]
+    secret: T,
+    #[cfg(test)]
+    tested: bool,
+    #[cfg(not(test))]
+    untested: bool,
+}
+
+#[derive(ShortHand, Default)]
+#[shorthand(views, enable(get_mut), verify(fn = "Self::verify"))]
+struct Verified {
+    value: usize,
+}
+
+impl Verified {
+    fn verify(&self) {}
+}
+
+#[derive(ShortHand, Default)]
+#[shorthand(views)]
+struct Skipped<T> {
+    #[shorthand(enable(skip))]
+    value: T,
+}
+
+#[test]
+fn test_fields_mut() {
+    let mut example = Example::<usize>::default();
+
+    let ExampleMut {
+        name,
+        values,
+        count,
+        tested,
+        ..
+    } = example.fields_mut();
+
+    // all fields are borrowed at the same time
+    name.push_str("name");
+    values.push(*count);
+    *tested = true;
+
+    assert_eq!(example.name(), &"name".to_string());
+    assert_eq!(example.values(), &vec![0]);
+    assert!(example.tested());
+}
+
+#[test]
+fn test_fields_mut_immutable() {
+    let mut example = Example::<usize>::default();
+
+    // only fields with a mutable getter without a guard are borrowed mutably,
+    // `&&mut usize` would not coerce to `&&usize`
+    let view = example.fields_mut();
+    let _: &&usize = &view.count;
+    let _: &&usize = &view.write_only;
+    let _: &&usize = &view.guarded;
+    let _: &&mut String = &view.name;
+
+    let mut verified = Verified::default();
+    let _: &&usize = &verified.fields_mut().value;
+}
+
+#[test]
+fn test_fields_ref() {
+    let mut example = Example::<usize>::default();
+    example.set_name("name".to_string()).set_values(vec![1]);
+
+    let view = example.fields_ref();
+
+    assert_eq!(view.name, &"name".to_string());
+    assert_eq!(view.values, &vec![1]);
+    assert_eq!(view.count, &0);
+}
+
+#[test]
+fn test_skipped_generic() {
+    let mut skipped = Skipped::<String>::default();
+
+    let _ = skipped.fields_ref();
+    let _ = skipped.fields_mut();
+}
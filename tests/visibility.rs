@@ -11,6 +11,11 @@ pub struct Command {
     index: usize,
     optional: Option<String>,
     current_dir: String,
+    #[shorthand(
+        visibility(get = "pub", set = "pub(crate)", get_mut = "pub(self)"),
+        enable(get_mut)
+    )]
+    status: usize,
 }
 
 fn main() {}